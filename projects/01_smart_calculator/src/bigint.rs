@@ -0,0 +1,513 @@
+// 任意精度整数 - 不依赖外部库，在 crate 内实现
+// 学习目标：Vec 操作、运算符重载（std::ops）、Ord/Display trait、经典算法（Knuth 长除法）
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// 十进制输出时每次处理 9 位
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+// 符号 + 绝对值，绝对值按 2^32 进制小端序存储，没有多余的高位 0
+// 零的绝对值是空 Vec，并且 negative 总是 false
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt {
+            negative: false,
+            magnitude: Vec::new(),
+        }
+    }
+
+    pub fn one() -> Self {
+        BigInt::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut magnitude = vec![value as u32, (value >> 32) as u32];
+        normalize(&mut magnitude);
+        BigInt {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    pub fn from_i64(value: i64) -> Self {
+        let mut result = BigInt::from_u64(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        normalize(&mut magnitude);
+        let negative = negative && !magnitude.is_empty();
+        BigInt {
+            negative,
+            magnitude,
+        }
+    }
+
+    // 解析十进制整数，允许前导的 + 或 -
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut magnitude = Vec::new();
+        let bytes = digits.as_bytes();
+        // 第一段长度取余数，保证后面每段正好 9 位
+        let first = match bytes.len() % DECIMAL_CHUNK_DIGITS {
+            0 => DECIMAL_CHUNK_DIGITS,
+            n => n,
+        };
+        let mut start = 0;
+        let mut end = first;
+        while start < bytes.len() {
+            let chunk: u32 = digits[start..end].parse().ok()?;
+            let scale = 10u32.pow((end - start) as u32);
+            mul_small_add(&mut magnitude, scale, chunk);
+            start = end;
+            end += DECIMAL_CHUNK_DIGITS;
+        }
+
+        Some(BigInt::from_parts(negative, magnitude))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.magnitude == [1]
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt {
+            negative: false,
+            magnitude: self.magnitude.clone(),
+        }
+    }
+
+    // 二进制位数（绝对值）
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => self.magnitude.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    // 能放进 i64 时返回 Some
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let mut value: u64 = 0;
+        for (index, &limb) in self.magnitude.iter().enumerate() {
+            value |= (limb as u64) << (32 * index);
+        }
+        if self.negative {
+            if value <= i64::MAX as u64 + 1 {
+                Some((value as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(value).ok()
+        }
+    }
+
+    // 转换为最接近的 f64，过大时得到无穷大
+    pub fn to_f64(&self) -> f64 {
+        let mut value = 0.0;
+        for &limb in self.magnitude.iter().rev() {
+            value = value * 4_294_967_296.0 + limb as f64;
+        }
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    // 右移（对绝对值操作，保留符号）
+    pub fn shr(&self, bits: u64) -> BigInt {
+        let limbs = (bits / 32) as usize;
+        if limbs >= self.magnitude.len() {
+            return BigInt::zero();
+        }
+        let magnitude = shr_bits(&self.magnitude[limbs..], (bits % 32) as u32);
+        BigInt::from_parts(self.negative, magnitude)
+    }
+
+    // 截断除法：商向零取整，余数与被除数同号；除数为零时返回 None
+    pub fn div_rem(&self, divisor: &BigInt) -> Option<(BigInt, BigInt)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = divrem_magnitude(&self.magnitude, &divisor.magnitude);
+        Some((
+            BigInt::from_parts(self.negative != divisor.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        ))
+    }
+
+    // 整数幂（快速幂）
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    // 最大公约数（结果非负）
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b).expect("除数不为零");
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    // 整数平方根（向下取整），负数返回 None
+    pub fn sqrt_floor(&self) -> Option<BigInt> {
+        if self.negative {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(BigInt::zero());
+        }
+        // 牛顿迭代：从一个不小于真实值的初值开始单调递减
+        let mut x = BigInt::one().shl_bits(self.bits().div_ceil(2));
+        loop {
+            let (quotient, _) = self.div_rem(&x).expect("除数不为零");
+            let next = (&x + &quotient).shr(1);
+            if next >= x {
+                return Some(x);
+            }
+            x = next;
+        }
+    }
+
+    fn shl_bits(&self, bits: u64) -> BigInt {
+        let mut magnitude = vec![0; (bits / 32) as usize];
+        magnitude.extend(shl_bits(&self.magnitude, (bits % 32) as u32));
+        BigInt::from_parts(self.negative, magnitude)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_magnitude(&self.magnitude, &other.magnitude));
+        }
+        // 符号不同：用绝对值大的减去绝对值小的
+        match cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(other.negative, sub_magnitude(&other.magnitude, &self.magnitude)),
+            _ => BigInt::from_parts(self.negative, sub_magnitude(&self.magnitude, &other.magnitude)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &(-other.clone())
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // 反复除以 10^9，得到从低到高的十进制分段
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = divrem_small(&magnitude, DECIMAL_CHUNK);
+            chunks.push(remainder);
+            magnitude = quotient;
+        }
+
+        let mut text = String::new();
+        if self.negative {
+            text.push('-');
+        }
+        let mut iter = chunks.iter().rev();
+        if let Some(first) = iter.next() {
+            text.push_str(&first.to_string());
+        }
+        for chunk in iter {
+            text.push_str(&format!("{:09}", chunk));
+        }
+        f.pad(&text)
+    }
+}
+
+// ---- 绝对值（小端序 u32 数组）上的基本运算 ----
+
+fn normalize(magnitude: &mut Vec<u32>) {
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    if a.len() != b.len() {
+        return a.len().cmp(&b.len());
+    }
+    for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+        if x != y {
+            return x.cmp(y);
+        }
+    }
+    Ordering::Equal
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (index, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + short.get(index).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+// 要求 a >= b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (index, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - b.get(index).copied().unwrap_or(0) as i64 - borrow;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        result.push(diff as u32);
+    }
+    normalize(&mut result);
+    result
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    normalize(&mut result);
+    result
+}
+
+// magnitude = magnitude * factor + addend
+fn mul_small_add(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in magnitude.iter_mut() {
+        let value = *limb as u64 * factor as u64 + carry;
+        *limb = value as u32;
+        carry = value >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+fn divrem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for (index, &limb) in a.iter().enumerate().rev() {
+        let value = (remainder << 32) | limb as u64;
+        quotient[index] = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+    normalize(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+fn shl_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for &limb in a {
+        result.push((limb << shift) | carry);
+        carry = limb >> (32 - shift);
+    }
+    result.push(carry);
+    result
+}
+
+fn shr_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        let mut result = a.to_vec();
+        normalize(&mut result);
+        return result;
+    }
+    let mut result = Vec::with_capacity(a.len());
+    for (index, &limb) in a.iter().enumerate() {
+        let high = a.get(index + 1).copied().unwrap_or(0);
+        result.push((limb >> shift) | (high << (32 - shift)));
+    }
+    normalize(&mut result);
+    result
+}
+
+// 长除法（Knuth《计算机程序设计艺术》算法 D）
+fn divrem_magnitude(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitude(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let (quotient, remainder) = divrem_small(u, v[0]);
+        let mut remainder = vec![remainder];
+        normalize(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    // 规范化：左移使除数最高位为 1，这样试商最多偏大 2
+    let shift = v[v.len() - 1].leading_zeros();
+    let vn = {
+        let mut shifted = shl_bits(v, shift);
+        shifted.truncate(v.len());
+        shifted
+    };
+    let mut un = shl_bits(u, shift);
+    if un.len() == u.len() {
+        un.push(0);
+    }
+
+    let n = vn.len();
+    let m = u.len() - n;
+    let base = 1u64 << 32;
+    let mut quotient = vec![0u32; m + 1];
+
+    for j in (0..=m).rev() {
+        // 用被除数最高两位除以除数最高位估计商
+        let numerator = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = numerator / vn[n - 1] as u64;
+        let mut rhat = numerator % vn[n - 1] as u64;
+        while qhat >= base || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        // 乘法并相减：un[j..=j+n] -= qhat * vn
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = qhat * vn[i] as u64 + carry;
+            carry = product >> 32;
+            let diff = un[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            un[i + j] = diff as u32;
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+        let diff = un[j + n] as i64 - borrow - carry as i64;
+        un[j + n] = diff as u32;
+
+        // 试商大了 1：加回一次除数
+        if diff < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = qhat as u32;
+    }
+
+    normalize(&mut quotient);
+    let remainder = shr_bits(&un[..n], shift);
+    (quotient, remainder)
+}
@@ -0,0 +1,843 @@
+// 计算器核心 - 状态、表达式求值、历史和会话
+// 学习目标：结构体封装状态、公开 API 的设计、递归求值、错误类型
+
+use std::collections::HashMap;
+
+use crate::complex::{self, Complex, ComplexSettings};
+use crate::error::CalcError;
+use crate::format::{FormatSettings, FractionStyle};
+use crate::functions::{Arity, FunctionRegistry};
+use crate::history::{HistoryEntry, Operation};
+use crate::matrix;
+use crate::number::{self, Base, Number, NumberMode};
+use crate::numeric::{self, NumericSettings};
+use crate::parser::{self, BinaryOp, Expr};
+use crate::session::{FunctionDefinition, Session};
+use crate::statistics;
+use crate::symbolic;
+use crate::units;
+use crate::value::Value;
+
+// 用户函数的最大调用深度，防止无限递归导致栈溢出
+const MAX_CALL_DEPTH: usize = 100;
+
+// sum 最多计算的项数
+const MAX_SUM_TERMS: i64 = 1_000_000;
+
+// 用户定义的函数，例如 f(x, y) = x^2 + y
+struct UserFunction {
+    params: Vec<String>, // 参数名
+    body: Expr,          // 函数体语法树
+    source: String,      // 函数体原文，用于显示
+}
+
+// 计算器的主要状态
+pub struct Calculator {
+    current_value: Value,            // 当前值（可变，可以带单位）
+    memory: HashMap<String, Value>,  // 变量存储（练习HashMap）
+    history: Vec<HistoryEntry>,      // 计算历史（练习Vec）
+    redo_stack: Vec<HistoryEntry>,   // 被撤销、可以重做的记录
+    functions: FunctionRegistry,  // 数学函数和常量
+    user_functions: HashMap<String, UserFunction>, // 用户定义的函数
+    mode: NumberMode,                // 计算模式：浮点、精确或 programmer
+    word_bits: u32,                  // programmer 模式的字长
+    base: Base,                      // programmer 模式的显示进制
+    numeric: NumericSettings,        // solve、integrate 的容差和迭代次数
+    complex: ComplexSettings,        // 是否开启复数运算、复数的显示方式
+    format: FormatSettings,          // 结果的显示格式
+}
+
+// 编译时检查：计算器可以交给其他线程使用，注册的函数因此必须是 Send + Sync
+const fn assert_send<T: Send>() {}
+const _: () = assert_send::<Calculator>();
+
+impl Calculator {
+    // 创建新的计算器实例
+    pub fn new() -> Self {
+        Calculator {
+            current_value: Value::zero(),
+            memory: HashMap::new(),
+            history: Vec::new(),
+            redo_stack: Vec::new(),
+            functions: FunctionRegistry::with_builtins(),
+            user_functions: HashMap::new(),
+            mode: NumberMode::Float,
+            word_bits: 64,
+            base: Base::Dec,
+            numeric: NumericSettings::default(),
+            complex: ComplexSettings::default(),
+            format: FormatSettings::default(),
+        }
+    }
+    
+    // 注册自定义函数，供嵌入代码扩展函数库；出错时和内置函数一样返回 CalcError
+    // 例如: calculator.register_function("double", Arity::Exact(1), |args| Ok(args[0] * 2.0));
+    pub fn register_function<F>(&mut self, name: &str, arity: Arity, body: F)
+    where
+        F: Fn(&[f64]) -> Result<f64, CalcError> + Send + Sync + 'static,
+    {
+        self.functions.register(name, arity, body);
+    }
+    
+    // 执行基本运算
+    pub fn calculate(&mut self, operator: char, operand: Value) -> Result<Value, CalcError> {
+        let op = BinaryOp::from_char(operator)
+            .ok_or_else(|| CalcError::InvalidInput(format!("不支持的操作符: {}", operator)))?;
+        let result = self.apply(op, &self.current_value, &operand)?;
+        let result = wrap_value(result, self.mode, self.word_bits)?;
+        
+        // 记录历史
+        self.record(Operation::Apply { operator, operand }, result.clone());
+        Ok(result)
+    }
+    
+    // 记录一次计算并更新当前值；新的计算会让之前撤销的记录无法再重做
+    fn record(&mut self, operation: Operation, result: Value) {
+        let previous = std::mem::replace(&mut self.current_value, result.clone());
+        self.history.push(HistoryEntry {
+            operation,
+            previous,
+            result,
+        });
+        self.redo_stack.clear();
+    }
+    
+    // 撤销最后一次计算，当前值恢复为计算前的值
+    pub fn undo(&mut self) -> Option<&HistoryEntry> {
+        let entry = self.history.pop()?;
+        self.current_value = entry.previous.clone();
+        self.redo_stack.push(entry);
+        self.redo_stack.last()
+    }
+    
+    // 重做最近一次撤销的计算
+    pub fn redo(&mut self) -> Option<&HistoryEntry> {
+        let entry = self.redo_stack.pop()?;
+        self.current_value = entry.result.clone();
+        self.history.push(entry);
+        self.history.last()
+    }
+    
+    // 把第 index 条历史（从 1 开始）的结果重新载入为当前值
+    pub fn recall(&mut self, index: usize) -> Result<Value, CalcError> {
+        let entry = index
+            .checked_sub(1)
+            .and_then(|i| self.history.get(i))
+            .ok_or_else(|| CalcError::History(format!("没有第 {} 条历史记录，共 {} 条", index, self.history.len())))?;
+        self.current_value = entry.result.clone();
+        Ok(entry.result.clone())
+    }
+    
+    // 从 start 开始，把第 first..=last 条历史中的运算依次重新执行一遍
+    // 回放的每一步都会记录为新的历史，返回这些新记录
+    pub fn replay(&mut self, first: usize, last: usize, start: Value) -> Result<Vec<HistoryEntry>, CalcError> {
+        if first == 0 || first > last || last > self.history.len() {
+            return Err(CalcError::History(format!("无效的历史范围: {}-{}", first, last)));
+        }
+        let operations: Vec<Operation> = self.history[first - 1..last]
+            .iter()
+            .map(|entry| entry.operation.clone())
+            .collect();
+        
+        // 先全部计算成功，再一次性写入历史
+        let mut value = start;
+        let mut replayed = Vec::new();
+        for operation in operations {
+            let result = match &operation {
+                Operation::Apply { operator, operand } => {
+                    let op = BinaryOp::from_char(*operator)
+                        .ok_or_else(|| CalcError::InvalidInput(format!("不支持的操作符: {}", operator)))?;
+                    wrap_value(self.apply(op, &value, operand)?, self.mode, self.word_bits)?
+                },
+                Operation::Evaluate(source) => self.evaluate(&parse_expression(source)?)?,
+            };
+            replayed.push(HistoryEntry {
+                operation,
+                previous: value,
+                result: result.clone(),
+            });
+            value = result;
+        }
+        
+        self.current_value = value;
+        self.history.extend(replayed.iter().cloned());
+        self.redo_stack.clear();
+        Ok(replayed)
+    }
+    
+    // 切换计算模式，当前值和变量一起转换
+    pub fn set_mode(&mut self, mode: NumberMode) -> Result<(), CalcError> {
+        self.reconvert(mode, self.word_bits)
+    }
+    
+    // 设置 programmer 模式的字长，当前值和变量按新字长回绕
+    pub fn set_word_bits(&mut self, bits: u32) -> Result<(), CalcError> {
+        if !number::WORD_SIZES.contains(&bits) {
+            return Err(CalcError::InvalidInput(format!("不支持的字长: {} (可选: 8, 16, 32, 64)", bits)));
+        }
+        self.reconvert(self.mode, bits)
+    }
+    
+    // 按新的模式和字长转换当前值和所有变量；任何一个转换失败时保持原状
+    fn reconvert(&mut self, mode: NumberMode, bits: u32) -> Result<(), CalcError> {
+        let convert = |value: &Value| wrap_value(value.convert(mode), mode, bits);
+        let current_value = convert(&self.current_value)?;
+        let mut memory = HashMap::new();
+        for (name, value) in &self.memory {
+            let value = convert(value).map_err(|error| CalcError::Math(format!("变量 {}: {}", name, error)))?;
+            memory.insert(name.clone(), value);
+        }
+        
+        self.mode = mode;
+        self.word_bits = bits;
+        self.current_value = current_value;
+        self.memory = memory;
+        Ok(())
+    }
+    
+    // 设置 programmer 模式的显示进制，只影响显示，不改变数值
+    pub fn set_base(&mut self, base: Base) {
+        self.base = base;
+    }
+    
+    pub fn mode(&self) -> NumberMode {
+        self.mode
+    }
+    
+    pub fn word_bits(&self) -> u32 {
+        self.word_bits
+    }
+    
+    pub fn base(&self) -> Base {
+        self.base
+    }
+    
+    pub fn numeric_settings(&self) -> NumericSettings {
+        self.numeric
+    }
+    
+    // 设置数值方法的容差和迭代次数
+    pub fn set_numeric_settings(&mut self, settings: NumericSettings) -> Result<(), CalcError> {
+        if !(settings.tolerance.is_finite() && settings.tolerance > 0.0) {
+            return Err(CalcError::InvalidInput(format!("容差必须是正数: {}", settings.tolerance)));
+        }
+        if !(1..=numeric::MAX_ITERATIONS_LIMIT).contains(&settings.max_iterations) {
+            return Err(CalcError::InvalidInput(format!(
+                "迭代次数必须在 1 到 {} 之间: {}",
+                numeric::MAX_ITERATIONS_LIMIT,
+                settings.max_iterations
+            )));
+        }
+        self.numeric = settings;
+        Ok(())
+    }
+    
+    pub fn complex_settings(&self) -> ComplexSettings {
+        self.complex
+    }
+    
+    pub fn set_complex_settings(&mut self, settings: ComplexSettings) {
+        self.complex = settings;
+    }
+    
+    pub fn format_settings(&self) -> FormatSettings {
+        self.format
+    }
+    
+    pub fn set_format_settings(&mut self, settings: FormatSettings) {
+        self.format = settings;
+    }
+    
+    // 显示数值：按 format 设置显示，programmer 模式下整数按选择的进制显示，复数可以按极坐标显示
+    pub fn format_value(&self, value: &Value) -> String {
+        let float = |part: f64| self.format.format_float(part);
+        let integer = value.magnitude().and_then(Number::to_i64);
+        match (self.mode, integer, value) {
+            (_, _, Value::List(items)) => {
+                let items: Vec<String> = items.iter().map(|item| self.format_value(item)).collect();
+                format!("[{}]", items.join(", "))
+            },
+            (_, _, Value::Complex(z)) if self.complex.polar => complex::format_polar(*z, &float),
+            (_, _, Value::Complex(z)) => complex::format_rect(*z, &float),
+            (NumberMode::Programmer, Some(integer), Value::Number(_)) => self.base.format(integer, self.word_bits),
+            (NumberMode::Programmer, Some(integer), Value::Quantity(quantity)) => {
+                format!("{} {}", self.base.format(integer, self.word_bits), quantity.unit)
+            },
+            (_, _, Value::Number(number)) => self.format.format_number(number),
+            (_, _, Value::Quantity(quantity)) => {
+                format!("{} {}", self.format.format_number(&quantity.magnitude), quantity.unit)
+            },
+        }
+    }
+    
+    // 显示计算结果：按分数显示的精确值附带近似的小数，例如 1/3 (≈ 0.333…)、53/10 km (≈ 5.3 km)
+    // 列表只显示元素本身
+    pub fn describe_value(&self, value: &Value) -> String {
+        let exact = match value.magnitude() {
+            Some(Number::Exact(exact)) if !exact.is_integer() => exact,
+            _ => return self.format_value(value),
+        };
+        if self.format.fractions == FractionStyle::Decimal {
+            return self.format_value(value);
+        }
+        let approximation = self.format.format_decimal(exact);
+        match value {
+            Value::Quantity(quantity) => {
+                format!("{} (≈ {} {})", self.format_value(value), approximation, quantity.unit)
+            },
+            _ => format!("{} (≈ {})", self.format_value(value), approximation),
+        }
+    }
+    
+    // 按 format 设置显示一条历史记录，history、undo、redo、replay 共用
+    pub fn format_entry(&self, entry: &HistoryEntry) -> String {
+        let result = self.format_value(&entry.result);
+        match &entry.operation {
+            Operation::Apply { operator, operand } => format!(
+                "{} {} {} = {}",
+                self.format_value(&entry.previous),
+                operator,
+                self.format_value(operand),
+                result
+            ),
+            Operation::Evaluate(source) => format!("{} = {}", source, result),
+        }
+    }
+    
+    // 按当前模式解析数字
+    pub fn parse_number(&self, text: &str) -> Option<Number> {
+        Number::parse_literal(text, self.mode)
+    }
+    
+    // 单位名称作为数值 1 个该单位，例如 km 就是 1 km；在当前模式下表示数值 1
+    fn unit_value(&self, name: &str) -> Option<Value> {
+        let unit = units::Unit::parse_symbol(name)?;
+        Some(Value::quantity(self.parse_number("1")?, unit))
+    }
+    
+    // 计算表达式的值
+    pub fn evaluate(&self, expr: &Expr) -> Result<Value, CalcError> {
+        self.evaluate_in(expr, &HashMap::new(), 0)
+    }
+    
+    // 在给定作用域中计算表达式（练习递归和模式匹配）
+    // 变量依次从函数参数、memory、常量、虚数单位 i、单位中解析；depth 是当前用户函数的调用深度
+    // programmer 模式下每一步的结果都回绕到当前字长
+    fn evaluate_in(&self, expr: &Expr, scope: &HashMap<String, Value>, depth: usize) -> Result<Value, CalcError> {
+        let value = match expr {
+            Expr::Number(text) => self
+                .parse_number(text)
+                .map(Value::Number)
+                .ok_or_else(|| CalcError::InvalidInput(format!("无效的数字: {}", text))),
+            Expr::Variable(name) => scope
+                .get(name)
+                .cloned()
+                .or_else(|| self.get_variable(name))
+                .or_else(|| self.functions.constant(name).map(Value::Number))
+                .or_else(|| (name == complex::IMAGINARY_UNIT).then_some(Value::Complex(Complex::I)))
+                .or_else(|| self.unit_value(name))
+                .ok_or_else(|| CalcError::UndefinedVariable(name.clone())),
+            Expr::Neg(operand) => Ok(self.evaluate_in(operand, scope, depth)?.neg()),
+            Expr::BitNot(operand) => self.evaluate_in(operand, scope, depth)?.bit_not(),
+            Expr::Binary(op, lhs, rhs) => {
+                let lhs = self.evaluate_in(lhs, scope, depth)?;
+                let rhs = self.evaluate_in(rhs, scope, depth)?;
+                self.apply(*op, &lhs, &rhs)
+            },
+            Expr::Convert(value, unit) => {
+                let value = self.evaluate_in(value, scope, depth)?;
+                value.convert_to(&units::unit_from_expr(unit)?)
+            },
+            // diff 和 simplify 先展开成普通表达式再求值，例如 x = 3 时 diff(x^2, x) = 6
+            Expr::Call(name, _) if symbolic::SYMBOLIC_FUNCTIONS.contains(&name.as_str()) => {
+                let expanded = self.expand_in(expr, depth)?;
+                self.evaluate_in(&expanded, scope, depth)
+            },
+            Expr::Call(name, args) if numeric::is_numeric_call(name, args) => {
+                self.numeric_call(name, args, scope, depth).map(|(value, _)| value)
+            },
+            Expr::List(items) => items
+                .iter()
+                .map(|item| self.evaluate_in(item, scope, depth))
+                .collect::<Result<Vec<Value>, CalcError>>()
+                .map(Value::List),
+            Expr::Equation(..) => Err(CalcError::InvalidInput(format!("方程只能作为 solve 的第一个参数: {}", expr))),
+            // if(条件, 真值, 假值) 只计算被选中的分支，递归函数靠它终止
+            Expr::Call(name, args) if name == "if" => {
+                if args.len() != 3 {
+                    return Err(CalcError::ArgumentCount {
+                        name: name.clone(),
+                        expected: "3".to_string(),
+                        actual: args.len(),
+                    });
+                }
+                let condition = self.evaluate_in(&args[0], scope, depth)?;
+                let branch = if !condition.is_zero() { &args[1] } else { &args[2] };
+                self.evaluate_in(branch, scope, depth)
+            },
+            Expr::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.evaluate_in(arg, scope, depth))
+                    .collect::<Result<Vec<Value>, CalcError>>()?;
+                match self.user_functions.get(name) {
+                    Some(function) => self.call_user_function(name, function, &args, depth),
+                    None if statistics::is_statistics_call(name, &args) => statistics::call(name, &args),
+                    None if matrix::is_matrix_call(name, &args) => matrix::call(name, &args),
+                    // 内置函数只处理纯数值，参数是列表时按元素计算：sqrt([1, 4, 9]) = [1, 2, 3]
+                    None => Value::broadcast(&args, &mut |args| {
+                        if complex::is_complex_call(name, args) {
+                            return complex::call(name, args);
+                        }
+                        let numbers = args
+                            .iter()
+                            .map(|arg| match arg {
+                                Value::Number(number) => Ok(number.clone()),
+                                Value::Complex(_) => Err(CalcError::Math(format!("函数 {} 需要实数参数: {}", name, arg))),
+                                _ => Err(CalcError::Unit(format!("函数 {} 的参数不能带单位: {}", name, arg))),
+                            })
+                            .collect::<Result<Vec<Number>, CalcError>>()?;
+                        // sqrt(-1) 这样超出实数定义域的参数，开启复数运算时按复数计算
+                        if complex::needs_complex(name, &numbers) {
+                            if !self.complex.enabled {
+                                return Err(complex::not_real(name, &numbers));
+                            }
+                            return complex::call(name, args);
+                        }
+                        self.functions.call(name, &numbers).map(Value::Number)
+                    }),
+                }
+            },
+        }?;
+        wrap_value(value, self.mode, self.word_bits)
+    }
+    
+    // 二元运算；开启复数运算时，负数的非整数次幂按复数计算：(-8)^(1/3) = 1 + 1.732…i
+    fn apply(&self, op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+        // programmer 模式的移位位数受当前字长限制
+        if self.mode == NumberMode::Programmer && matches!(op, BinaryOp::Shl | BinaryOp::Shr) {
+            check_shift(rhs, self.word_bits)?;
+        }
+        match Value::apply(op, lhs, rhs) {
+            Err(CalcError::NotReal(_)) if self.complex.enabled => {
+                Value::broadcast(&[lhs.clone(), rhs.clone()], &mut |pair| match Value::apply(op, &pair[0], &pair[1]) {
+                    Err(CalcError::NotReal(_)) => complex::apply(op, &pair[0], &pair[1]),
+                    result => result,
+                })
+            },
+            result => result,
+        }
+    }
+    
+    // 调用用户函数：检查参数个数和递归深度，再在新作用域中计算函数体
+    fn call_user_function(&self, name: &str, function: &UserFunction, args: &[Value], depth: usize) -> Result<Value, CalcError> {
+        if args.len() != function.params.len() {
+            return Err(CalcError::ArgumentCount {
+                name: name.to_string(),
+                expected: function.params.len().to_string(),
+                actual: args.len(),
+            });
+        }
+        
+        if depth >= MAX_CALL_DEPTH {
+            return Err(CalcError::RecursionLimit {
+                name: name.to_string(),
+                limit: MAX_CALL_DEPTH,
+            });
+        }
+        
+        let scope: HashMap<String, Value> = function
+            .params
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect();
+        // 只在最内层的函数上标注出错位置
+        self.evaluate_in(&function.body, &scope, depth + 1)
+            .map_err(|error| error.in_function(name))
+    }
+    
+    // solve、integrate、sum：表达式在变量取不同值时反复计算，返回结果和收敛情况的说明
+    fn numeric_call(
+        &self,
+        name: &str,
+        args: &[Expr],
+        scope: &HashMap<String, Value>,
+        depth: usize,
+    ) -> Result<(Value, Option<String>), CalcError> {
+        let usage = || {
+            let usage = match name {
+                "solve" => "solve(方程, 变量名, 初始值) 或 solve(方程, 变量名, 下限, 上限)",
+                "integrate" => "integrate(表达式, 变量名, 下限, 上限)",
+                _ => "sum(表达式, 变量名, 起始值, 结束值)",
+            };
+            CalcError::InvalidInput(format!("{} 的用法是 {}", name, usage))
+        };
+        let [body, Expr::Variable(var), bounds @ ..] = args else {
+            return Err(usage());
+        };
+        let bounds: Vec<Value> = bounds
+            .iter()
+            .map(|bound| self.evaluate_in(bound, scope, depth))
+            .collect::<Result<_, _>>()?;
+        
+        if name == "sum" {
+            let [first, last] = bounds.as_slice() else {
+                return Err(usage());
+            };
+            return Ok((self.sum(body, var, first, last, scope, depth)?, None));
+        }
+        
+        let bounds = bounds
+            .iter()
+            .map(|bound| {
+                bound.as_number().map(Number::to_f64).ok_or_else(|| {
+                    CalcError::Unit(format!("{} 的范围和初始值不能带单位: {}", name, bound))
+                })
+            })
+            .collect::<Result<Vec<f64>, CalcError>>()?;
+        
+        // 方程 左边 = 右边 转换为求 左边 - 右边 的零点
+        let target = match body {
+            Expr::Equation(lhs, rhs) if name == "solve" => Expr::Binary(BinaryOp::Sub, lhs.clone(), rhs.clone()),
+            _ => body.clone(),
+        };
+        let mut f = self.real_function(name, &target, var, scope, depth);
+        match (name, bounds.as_slice()) {
+            ("solve", [guess]) => {
+                // 能够符号求导时用精确的导数，否则牛顿法用差分近似
+                let derivative = self
+                    .expand_in(&target, depth)
+                    .and_then(|expr| self.inline_functions(&expr, depth))
+                    .and_then(|expr| symbolic::diff(&expr, var))
+                    .ok();
+                let mut df = derivative.as_ref().map(|derivative| self.real_function(name, derivative, var, scope, depth));
+                let df = df.as_mut().map(|df| df as &mut numeric::RealFn);
+                let solution = numeric::solve(&mut f, df, *guess, self.numeric)?;
+                Ok((Value::Number(Number::Float(solution.root)), Some(solution.to_string())))
+            },
+            ("solve", [a, b]) => {
+                let solution = numeric::bisection(&mut f, *a, *b, self.numeric)?;
+                Ok((Value::Number(Number::Float(solution.root)), Some(solution.to_string())))
+            },
+            ("integrate", [a, b]) => {
+                let integral = numeric::integrate(&mut f, *a, *b, self.numeric)?;
+                Ok((Value::Number(Number::Float(integral.value)), Some(integral.to_string())))
+            },
+            _ => Err(usage()),
+        }
+    }
+    
+    // 把表达式包装成 f64 -> f64 的函数，变量 var 取参数的值
+    fn real_function<'a>(
+        &'a self,
+        name: &'a str,
+        expr: &'a Expr,
+        var: &'a str,
+        scope: &'a HashMap<String, Value>,
+        depth: usize,
+    ) -> impl FnMut(f64) -> Result<f64, CalcError> + 'a {
+        move |x| {
+            let mut scope = scope.clone();
+            scope.insert(var.to_string(), Value::Number(Number::Float(x)));
+            let value = self.evaluate_in(expr, &scope, depth)?;
+            value
+                .as_number()
+                .map(Number::to_f64)
+                .ok_or_else(|| CalcError::Unit(format!("{} 中的表达式必须是不带单位的实数: {}", name, value)))
+        }
+    }
+    
+    // 求和：变量依次取 first 到 last 的整数，保持精确模式和单位
+    fn sum(
+        &self,
+        body: &Expr,
+        var: &str,
+        first: &Value,
+        last: &Value,
+        scope: &HashMap<String, Value>,
+        depth: usize,
+    ) -> Result<Value, CalcError> {
+        let integer_bound = |bound: &Value| {
+            bound
+                .as_number()
+                .and_then(|number| number.convert(NumberMode::Exact).as_integer())
+                .and_then(|integer| integer.to_i64())
+                .ok_or_else(|| CalcError::InvalidInput(format!("sum 的起始值和结束值必须是整数: {}", bound)))
+        };
+        let (first, last) = (integer_bound(first)?, integer_bound(last)?);
+        if last.saturating_sub(first) >= MAX_SUM_TERMS {
+            return Err(CalcError::InvalidInput(format!("sum 最多计算 {} 项", MAX_SUM_TERMS)));
+        }
+        
+        let mut scope = scope.clone();
+        let mut total: Option<Value> = None;
+        for i in first..=last {
+            let index = self.parse_number(&i.to_string()).expect("整数字面量");
+            scope.insert(var.to_string(), Value::Number(index));
+            let term = self.evaluate_in(body, &scope, depth)?;
+            total = Some(match total {
+                Some(total) => self.apply(BinaryOp::Add, &total, &term)?,
+                None => term,
+            });
+        }
+        // 空的和是 0
+        Ok(total.unwrap_or_else(Value::zero))
+    }
+    
+    // 展开表达式中的 diff(表达式, 变量) 和 simplify(表达式)，得到符号结果
+    pub fn expand_symbolic(&self, expr: &Expr) -> Result<Expr, CalcError> {
+        self.expand_in(expr, 0)
+    }
+    
+    fn expand_in(&self, expr: &Expr, depth: usize) -> Result<Expr, CalcError> {
+        match expr {
+            Expr::Call(name, args) if name == "diff" => {
+                let [body, Expr::Variable(var)] = args.as_slice() else {
+                    return Err(CalcError::InvalidInput("diff 的用法是 diff(表达式, 变量名)".to_string()));
+                };
+                // 求导前展开用户函数：f(x) = x^2 时 diff(f(x), x) = 2 * x
+                let body = self.inline_functions(&self.expand_in(body, depth)?, depth)?;
+                symbolic::diff(&body, var)
+            },
+            Expr::Call(name, args) if name == "simplify" => {
+                let [body] = args.as_slice() else {
+                    return Err(CalcError::ArgumentCount {
+                        name: name.clone(),
+                        expected: "1".to_string(),
+                        actual: args.len(),
+                    });
+                };
+                Ok(symbolic::simplify(&self.expand_in(body, depth)?))
+            },
+            _ => symbolic::map_children(expr, |child| self.expand_in(child, depth)),
+        }
+    }
+    
+    // 把用户函数调用替换成代入参数后的函数体
+    fn inline_functions(&self, expr: &Expr, depth: usize) -> Result<Expr, CalcError> {
+        if let Expr::Call(name, args) = expr {
+            if let Some(function) = self.user_functions.get(name) {
+                if args.len() != function.params.len() {
+                    return Err(CalcError::ArgumentCount {
+                        name: name.clone(),
+                        expected: function.params.len().to_string(),
+                        actual: args.len(),
+                    });
+                }
+                if depth >= MAX_CALL_DEPTH {
+                    return Err(CalcError::RecursionLimit {
+                        name: name.clone(),
+                        limit: MAX_CALL_DEPTH,
+                    });
+                }
+                
+                let mut bindings = HashMap::new();
+                for (param, arg) in function.params.iter().zip(args) {
+                    bindings.insert(param.clone(), self.inline_functions(arg, depth)?);
+                }
+                let body = self.expand_in(&function.body, depth + 1)?;
+                return self.inline_functions(&symbolic::substitute(&body, &bindings), depth + 1);
+            }
+        }
+        symbolic::map_children(expr, |child| self.inline_functions(child, depth))
+    }
+    
+    // 定义或重新定义用户函数，返回是否覆盖了已有定义
+    pub fn define_function(&mut self, name: &str, params: Vec<String>, source: &str) -> Result<bool, CalcError> {
+        let special = symbolic::SYMBOLIC_FUNCTIONS.contains(&name)
+            || numeric::NUMERIC_FUNCTIONS.contains(&name)
+            || statistics::STATISTICS_FUNCTIONS.contains(&name)
+            || matrix::MATRIX_FUNCTIONS.contains(&name)
+            || complex::COMPLEX_FUNCTIONS.contains(&name);
+        if name == "if" || special || self.functions.function_names().contains(&name) {
+            return Err(CalcError::InvalidInput(format!("不能重新定义内置函数: {}", name)));
+        }
+        
+        for (index, param) in params.iter().enumerate() {
+            if !parser::is_identifier(param) {
+                return Err(CalcError::InvalidInput(format!("无效的参数名: {}", param)));
+            }
+            if params[..index].contains(param) {
+                return Err(CalcError::InvalidInput(format!("参数名重复: {}", param)));
+            }
+        }
+        
+        let body = parse_expression(source)?;
+        let function = UserFunction {
+            params,
+            body,
+            source: source.to_string(),
+        };
+        Ok(self.user_functions.insert(name.to_string(), function).is_some())
+    }
+    
+    // 导出需要持久化的状态（变量和函数按名称排序，保证文件内容稳定）
+    pub fn snapshot(&self) -> Session {
+        let variables = self
+            .variables()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        
+        Session {
+            mode: self.mode,
+            word_bits: self.word_bits,
+            base: self.base,
+            numeric: self.numeric,
+            complex: self.complex,
+            format: self.format,
+            value: self.current_value.clone(),
+            variables,
+            functions: self.function_definitions(),
+            history: self.history.clone(),
+        }
+    }
+    
+    // 用会话替换当前状态；函数定义全部检查通过后才会生效
+    pub fn restore(&mut self, session: Session) -> Result<(), CalcError> {
+        let mut restored = Calculator::new();
+        for function in session.functions {
+            restored.define_function(&function.name, function.params, &function.body)?;
+        }
+        
+        self.user_functions = restored.user_functions;
+        self.mode = session.mode;
+        self.word_bits = session.word_bits;
+        self.base = session.base;
+        self.numeric = session.numeric;
+        self.complex = session.complex;
+        self.format = session.format;
+        self.current_value = session.value;
+        self.memory = session.variables.into_iter().collect();
+        self.history = session.history;
+        self.redo_stack.clear();
+        Ok(())
+    }
+    
+    // 所有用户函数的定义，按名称排序
+    pub fn function_definitions(&self) -> Vec<FunctionDefinition> {
+        let mut functions: Vec<FunctionDefinition> = self
+            .user_functions
+            .iter()
+            .map(|(name, function)| FunctionDefinition {
+                name: name.clone(),
+                params: function.params.clone(),
+                body: function.source.clone(),
+            })
+            .collect();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        functions
+    }
+    
+    // 内置函数和常量，用于列出可用的名称
+    pub fn functions(&self) -> &FunctionRegistry {
+        &self.functions
+    }
+    
+    // 计算完整的表达式并把结果作为当前值
+    pub fn evaluate_input(&mut self, input: &str) -> Result<Value, CalcError> {
+        Ok(self.evaluate_input_with_report(input)?.0)
+    }
+    
+    // 和 evaluate_input 相同；最外层是 solve 或 integrate 时同时返回收敛情况的说明
+    pub fn evaluate_input_with_report(&mut self, input: &str) -> Result<(Value, Option<String>), CalcError> {
+        let expr = parse_expression(input)?;
+        let (result, report) = match &expr {
+            Expr::Call(name, args) if numeric::is_numeric_call(name, args) => {
+                let (value, report) = self.numeric_call(name, args, &HashMap::new(), 0)?;
+                (wrap_value(value, self.mode, self.word_bits)?, report)
+            },
+            _ => (self.evaluate(&expr)?, None),
+        };
+        
+        self.record(Operation::Evaluate(input.to_string()), result.clone());
+        Ok((result, report))
+    }
+    
+    // 设置当前值
+    pub fn set_value(&mut self, value: Value) {
+        self.current_value = value;
+    }
+    
+    // 获取当前值
+    pub fn get_value(&self) -> &Value {
+        &self.current_value
+    }
+    
+    // 存储变量
+    pub fn store_variable(&mut self, name: String, value: Value) {
+        self.memory.insert(name, value);
+    }
+    
+    // 获取变量值
+    pub fn get_variable(&self, name: &str) -> Option<Value> {
+        self.memory.get(name).cloned()
+    }
+    
+    // 全部历史记录，按时间顺序
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+    
+    // 清除历史
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.redo_stack.clear();
+    }
+    
+    // 所有变量（按名称排序，输出稳定）
+    pub fn variables(&self) -> Vec<(&str, &Value)> {
+        let mut variables: Vec<(&str, &Value)> = self
+            .memory
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        variables.sort_by(|a, b| a.0.cmp(b.0));
+        variables
+    }
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Calculator::new()
+    }
+}
+
+// programmer 模式下把值回绕到 bits 位有符号整数，其他模式原样返回；复数没有对应的整数，直接报错
+pub(crate) fn wrap_value(value: Value, mode: NumberMode, bits: u32) -> Result<Value, CalcError> {
+    if mode != NumberMode::Programmer {
+        return Ok(value);
+    }
+    check_not_complex(&value)?;
+    value.map_magnitude(&|magnitude| magnitude.wrap_to_word(bits))
+}
+
+// programmer 模式的值不能是复数，列表逐个检查
+fn check_not_complex(value: &Value) -> Result<(), CalcError> {
+    match value {
+        Value::Complex(_) => Err(CalcError::Math(format!("programmer 模式不支持复数: {}", value))),
+        Value::List(items) => items.iter().try_for_each(check_not_complex),
+        _ => Ok(()),
+    }
+}
+
+// programmer 模式的移位位数必须是 0 到字长 - 1 之间的整数，列表逐个检查
+fn check_shift(shift: &Value, bits: u32) -> Result<(), CalcError> {
+    match shift {
+        Value::Number(number) => match number.to_i64() {
+            Some(count) if (0..i64::from(bits)).contains(&count) => Ok(()),
+            _ => Err(CalcError::Math(format!("移位位数必须是 0 到 {} 之间的整数（当前字长 {} 位）", bits - 1, bits))),
+        },
+        Value::List(items) => items.iter().try_for_each(|item| check_shift(item, bits)),
+        _ => Ok(()),
+    }
+}
+
+// 解析表达式，出错时保留原始输入，用于显示指向出错列的提示
+pub fn parse_expression(input: &str) -> Result<Expr, CalcError> {
+    parser::parse(input).map_err(|error| CalcError::Parse {
+        error,
+        input: input.to_string(),
+    })
+}
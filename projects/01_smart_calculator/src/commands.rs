@@ -0,0 +1,544 @@
+// 命令解释 - 把一行输入解释为命令或表达式，交互模式和脚本模式共用
+// 学习目标：字符串匹配、枚举表示不同的输出、? 传播错误
+
+use crate::calculator::{self, Calculator};
+use crate::complex;
+use crate::error::CalcError;
+use crate::format::{FormatSettings, FractionStyle, Notation};
+use crate::history;
+use crate::matrix;
+use crate::number::{Base, NumberMode};
+use crate::numeric::{self, NumericSettings};
+use crate::parser::{self, Expr};
+use crate::session;
+use crate::statistics;
+use crate::symbolic;
+use crate::value::Value;
+
+// 执行一行输入后的输出
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reply {
+    Result(Value),        // 计算结果
+    Report(Value, String), // 计算结果和收敛情况，例如 solve 的迭代次数，脚本模式只输出结果
+    Expression(Expr),     // 符号运算的结果，例如 diff(x^2, x) 得到 2 * x
+    Message(String),      // 提示信息，只在交互模式显示
+    Listing(Vec<String>), // 历史、变量、帮助等列表，两种模式都显示
+    Nothing,              // 空行或注释
+    Quit,                 // 退出命令
+}
+
+// 可以用 Tab 补全的命令
+const COMMAND_NAMES: [&str; 22] = [
+    "help", "history", "undo", "redo", "clear_history", "variables", "functions", "mode", "clear", "save",
+    "load", "recall", "replay", "word", "base", "complex", "format", "tolerance", "iterations", "quit", "exit",
+    "vars",
+];
+
+// 执行一行输入（命令或表达式），交互模式和脚本模式共用
+pub fn execute(calculator: &mut Calculator, line: &str) -> Result<Reply, CalcError> {
+    // # 之后的内容是注释
+    let input = line.split('#').next().unwrap_or("").trim();
+    
+    // 处理特殊命令
+    let reply = match input {
+        "" => Reply::Nothing,
+        "quit" | "q" | "exit" => Reply::Quit,
+        "help" | "h" => Reply::Listing(help_lines(calculator)),
+        "history" => history_listing(calculator),
+        "undo" => match calculator.undo().cloned() {
+            Some(entry) => Reply::Message(format!("已撤销: {}", calculator.format_entry(&entry))),
+            None => Reply::Message("没有可以撤销的计算".to_string()),
+        },
+        "redo" => match calculator.redo().cloned() {
+            Some(entry) => Reply::Message(format!("已重做: {}", calculator.format_entry(&entry))),
+            None => Reply::Message("没有可以重做的计算".to_string()),
+        },
+        "clear_history" => {
+            calculator.clear_history();
+            Reply::Message("历史记录已清除".to_string())
+        },
+        "variables" | "vars" => variables_listing(calculator),
+        "functions" | "funcs" => functions_listing(calculator),
+        "mode" => Reply::Message(format!("当前计算模式: {}", calculator.mode().name())),
+        "clear" | "c" => {
+            calculator.set_value(Value::zero());
+            Reply::Message("计算器已重置".to_string())
+        },
+        _ if input.starts_with("save ") => {
+            let path = input["save ".len()..].trim();
+            session::save(path, &calculator.snapshot())?;
+            Reply::Message(format!("会话已保存到: {}", path))
+        },
+        _ if input.starts_with("load ") => {
+            let path = input["load ".len()..].trim();
+            calculator.restore(session::load(path)?)?;
+            Reply::Message(format!("已从 {} 加载会话", path))
+        },
+        _ if input.starts_with("recall ") => {
+            let index = input["recall ".len()..].trim();
+            let index = index
+                .parse::<usize>()
+                .map_err(|_| CalcError::History(format!("无效的历史编号: {}", index)))?;
+            let value = calculator.recall(index)?;
+            Reply::Message(format!("当前值设置为: {}", calculator.format_value(&value)))
+        },
+        "replay" => return Err(CalcError::InvalidInput("replay 的用法是 replay A-B [值]".to_string())),
+        _ if input.starts_with("replay ") => handle_replay(calculator, input["replay ".len()..].trim())?,
+        _ if input.starts_with("mode ") => {
+            let name = input["mode ".len()..].trim();
+            let mode = NumberMode::from_name(name)
+                .ok_or_else(|| CalcError::InvalidInput(format!("未知的计算模式: {} (可选: exact, float, programmer)", name)))?;
+            calculator.set_mode(mode)?;
+            Reply::Message(format!("计算模式切换为: {}", mode.name()))
+        },
+        "word" => Reply::Message(format!("当前字长: {} 位", calculator.word_bits())),
+        _ if input.starts_with("word ") => {
+            let bits = input["word ".len()..].trim();
+            let bits = bits
+                .parse::<u32>()
+                .map_err(|_| CalcError::InvalidInput(format!("不支持的字长: {} (可选: 8, 16, 32, 64)", bits)))?;
+            calculator.set_word_bits(bits)?;
+            Reply::Message(format!("字长设置为: {} 位", bits))
+        },
+        "base" => Reply::Message(format!("当前显示进制: {}", calculator.base().name())),
+        _ if input.starts_with("base ") => {
+            let name = input["base ".len()..].trim();
+            let base = Base::from_name(name)
+                .ok_or_else(|| CalcError::InvalidInput(format!("未知的进制: {} (可选: hex, bin, oct, dec)", name)))?;
+            calculator.set_base(base);
+            Reply::Message(format!("显示进制切换为: {}", name))
+        },
+        "complex" => {
+            let settings = calculator.complex_settings();
+            Reply::Message(format!(
+                "复数运算: {}，显示方式: {}",
+                if settings.enabled { "on" } else { "off" },
+                if settings.polar { "polar" } else { "rect" }
+            ))
+        },
+        _ if input.starts_with("complex ") => {
+            let mut settings = calculator.complex_settings();
+            let option = input["complex ".len()..].trim();
+            let message = match option {
+                "on" => {
+                    settings.enabled = true;
+                    "复数运算已开启，sqrt(-1) = i"
+                },
+                "off" => {
+                    settings.enabled = false;
+                    "复数运算已关闭，结果是复数的实数运算会报错"
+                },
+                "polar" => {
+                    settings.polar = true;
+                    "复数按极坐标显示: 模长∠辐角（弧度）"
+                },
+                "rect" => {
+                    settings.polar = false;
+                    "复数按直角坐标显示: 实部 + 虚部i"
+                },
+                _ => {
+                    return Err(CalcError::InvalidInput(format!(
+                        "未知的复数设置: {} (可选: on, off, polar, rect)",
+                        option
+                    )))
+                },
+            };
+            calculator.set_complex_settings(settings);
+            Reply::Message(message.to_string())
+        },
+        "format" => Reply::Message(describe_format(calculator.format_settings())),
+        _ if input.starts_with("format ") => handle_format(calculator, input["format ".len()..].trim())?,
+        "tolerance" => Reply::Message(format!("当前容差: {:e}", calculator.numeric_settings().tolerance)),
+        _ if input.starts_with("tolerance ") => {
+            let text = input["tolerance ".len()..].trim();
+            let tolerance = NumericSettings::parse_tolerance(text)
+                .ok_or_else(|| CalcError::InvalidInput(format!("容差必须是正数: {}", text)))?;
+            calculator.set_numeric_settings(NumericSettings { tolerance, ..calculator.numeric_settings() })?;
+            Reply::Message(format!("容差设置为: {:e}", tolerance))
+        },
+        "iterations" => Reply::Message(format!("当前最大迭代次数: {}", calculator.numeric_settings().max_iterations)),
+        _ if input.starts_with("iterations ") => {
+            let text = input["iterations ".len()..].trim();
+            let max_iterations = NumericSettings::parse_iterations(text).ok_or_else(|| {
+                CalcError::InvalidInput(format!("迭代次数必须在 1 到 {} 之间: {}", numeric::MAX_ITERATIONS_LIMIT, text))
+            })?;
+            calculator.set_numeric_settings(NumericSettings { max_iterations, ..calculator.numeric_settings() })?;
+            Reply::Message(format!("最大迭代次数设置为: {}", max_iterations))
+        },
+        // 处理计算表达式
+        _ => process_input(calculator, input)?,
+    };
+    Ok(reply)
+}
+
+// 处理用户输入的计算表达式
+fn process_input(calculator: &mut Calculator, input: &str) -> Result<Reply, CalcError> {
+    // 检查是否是函数定义 (例如: f(x, y) = x^2 + y) 或变量赋值 (例如: x = 42)
+    // 括号里的等号属于 solve 的方程 (例如: solve(x^2 = 2, x, 1))
+    if let Some((target, body)) = split_assignment(input) {
+        if target.contains('(') {
+            return handle_function_definition(calculator, target, body);
+        }
+        return handle_variable_assignment(calculator, target, body);
+    }
+    
+    // 检查是否是单独的数字或变量名；和表达式一样作为结果返回，批处理和 --serve 模式才能拿到这个值
+    if let Some(number) = calculator.parse_number(input) {
+        let value = calculator::wrap_value(Value::Number(number), calculator.mode(), calculator.word_bits())?;
+        calculator.set_value(value.clone());
+        return Ok(Reply::Result(value));
+    }
+    
+    // 检查是否是变量名
+    if let Some(value) = calculator.get_variable(input) {
+        calculator.set_value(value.clone());
+        return Ok(Reply::Result(value));
+    }
+    
+    // 以运算符开头时对当前值继续运算 (例如: + 5, * (2 + x), ^ 3)
+    // + 和 - 后面必须有空格，否则按一元正负号处理 (例如: -x * 2)
+    if is_continuation(input) {
+        return parse_operation(calculator, input);
+    }
+    
+    // 最外层是 diff 或 simplify 时给出符号结果，不改变当前值 (例如: diff(x^2 * sin(x), x))
+    let expr = calculator::parse_expression(input)?;
+    if symbolic::is_symbolic_call(&expr) {
+        return Ok(Reply::Expression(calculator.expand_symbolic(&expr)?));
+    }
+    
+    // 其余情况按完整表达式计算 (例如: 3 + 4 * (2 - x) ^ 2)
+    // solve 和 integrate 同时给出收敛情况 (例如: solve(x^2 = 2, x, 1))
+    match calculator.evaluate_input_with_report(input)? {
+        (result, Some(report)) => Ok(Reply::Report(result, report)),
+        (result, None) => Ok(Reply::Result(result)),
+    }
+}
+
+// 在括号和方括号外的第一个等号处拆分赋值语句
+fn split_assignment(input: &str) -> Option<(&str, &str)> {
+    if input.contains("==") {
+        return None;
+    }
+    let mut depth = 0;
+    for (index, ch) in input.char_indices() {
+        match ch {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '=' if depth == 0 => return Some((&input[..index], &input[index + 1..])),
+            _ => {},
+        }
+    }
+    None
+}
+
+// 列出历史记录
+fn history_listing(calculator: &Calculator) -> Reply {
+    if calculator.history().is_empty() {
+        return Reply::Message("没有计算历史".to_string());
+    }
+    
+    let mut lines = vec!["=== 计算历史 ===".to_string()];
+    for (index, entry) in calculator.history().iter().enumerate() {
+        lines.push(format!("{}: {}", index + 1, calculator.format_entry(entry)));
+    }
+    Reply::Listing(lines)
+}
+
+// 列出所有变量
+fn variables_listing(calculator: &Calculator) -> Reply {
+    let variables = calculator.variables();
+    if variables.is_empty() {
+        return Reply::Message("没有存储的变量".to_string());
+    }
+    
+    let mut lines = vec!["=== 存储的变量 ===".to_string()];
+    for (name, value) in variables {
+        lines.push(format!("{} = {}", name, calculator.format_value(value)));
+    }
+    Reply::Listing(lines)
+}
+
+// 列出所有用户函数
+fn functions_listing(calculator: &Calculator) -> Reply {
+    let functions = calculator.function_definitions();
+    if functions.is_empty() {
+        return Reply::Message("没有自定义函数".to_string());
+    }
+    
+    let mut lines = vec!["=== 自定义函数 ===".to_string()];
+    for function in functions {
+        lines.push(format!("{}({}) = {}", function.name, function.params.join(", "), function.body));
+    }
+    Reply::Listing(lines)
+}
+
+// 判断输入是否是对当前值的继续运算
+fn is_continuation(input: &str) -> bool {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some('*') | Some('/') | Some('^') => true,
+        Some('+') | Some('-') => chars.next().is_some_and(|ch| ch.is_whitespace()),
+        _ => false,
+    }
+}
+
+// 处理变量赋值
+fn handle_variable_assignment(calculator: &mut Calculator, target: &str, body: &str) -> Result<Reply, CalcError> {
+    let var_name = target.trim().to_string();
+    let value_str = body.trim();
+    
+    if !parser::is_identifier(&var_name) {
+        return Err(CalcError::InvalidInput(format!("无效的变量名: {}", var_name)));
+    }
+    
+    // 值可以是任意表达式 (例如: y = x * 2 + 1)
+    let expr = calculator::parse_expression(value_str)?;
+    let value = calculator.evaluate(&expr)?;
+    
+    calculator.store_variable(var_name.clone(), value.clone());
+    Ok(Reply::Message(format!("变量 {} 设置为: {}", var_name, calculator.format_value(&value))))
+}
+
+// 处理回放命令: replay 范围 [初始值]
+fn handle_replay(calculator: &mut Calculator, args: &str) -> Result<Reply, CalcError> {
+    let (range, start) = args.split_once(' ').unwrap_or((args, ""));
+    let (first, last) = history::parse_range(range, calculator.history().len())?;
+    
+    // 初始值可以是任意表达式，省略时从当前值开始
+    let start = if start.trim().is_empty() {
+        calculator.get_value().clone()
+    } else {
+        calculator.evaluate(&calculator::parse_expression(start.trim())?)?
+    };
+    
+    let mut lines = vec![format!("从 {} 开始回放第 {} 到 {} 条:", calculator.format_value(&start), first, last)];
+    for entry in calculator.replay(first, last, start)? {
+        lines.push(format!("  {}", calculator.format_entry(&entry)));
+    }
+    lines.push(format!("结果: {}", calculator.describe_value(calculator.get_value())));
+    Ok(Reply::Listing(lines))
+}
+
+// 当前的输出格式
+fn describe_format(settings: FormatSettings) -> String {
+    format!(
+        "输出格式: {}，千位分隔符: {}，分数显示: {}",
+        settings.notation.name(),
+        if settings.separators { "on" } else { "off" },
+        settings.fractions.name()
+    )
+}
+
+// 处理输出格式命令: format auto|fix N|sig N|sci N|eng N、format sep on|off、format fraction|mixed|decimal
+fn handle_format(calculator: &mut Calculator, option: &str) -> Result<Reply, CalcError> {
+    let mut settings = calculator.format_settings();
+    match option {
+        "reset" => settings = FormatSettings::default(),
+        "sep on" => settings.separators = true,
+        "sep off" => settings.separators = false,
+        _ => {
+            if let Some(fractions) = FractionStyle::from_name(option) {
+                settings.fractions = fractions;
+            } else if let Some(notation) = Notation::parse(option) {
+                settings.notation = notation;
+            } else {
+                return Err(CalcError::InvalidInput(format!(
+                    "未知的输出格式: {} (可选: auto, fix N, sig N, sci N, eng N, sep on|off, fraction, mixed, decimal, reset)",
+                    option
+                )));
+            }
+        },
+    }
+    calculator.set_format_settings(settings);
+    Ok(Reply::Message(describe_format(settings)))
+}
+
+// 处理函数定义
+fn handle_function_definition(calculator: &mut Calculator, signature: &str, body: &str) -> Result<Reply, CalcError> {
+    let format_error = || CalcError::InvalidInput("函数定义格式错误，应该是: 函数名(参数, ...) = 表达式".to_string());
+    
+    // 拆分函数签名 f(x, y)
+    let signature = signature.trim();
+    let (name, params) = parser::split_signature(signature).ok_or_else(format_error)?;
+    
+    if !parser::is_identifier(&name) {
+        return Err(CalcError::InvalidInput(format!("无效的函数名: {}", name)));
+    }
+    
+    let body = body.trim();
+    let redefined = calculator.define_function(&name, params, body)?;
+    if redefined {
+        Ok(Reply::Message(format!("函数 {} 已重新定义: {} = {}", name, signature, body)))
+    } else {
+        Ok(Reply::Message(format!("函数 {} 已定义: {} = {}", name, signature, body)))
+    }
+}
+
+// 解析运算操作
+fn parse_operation(calculator: &mut Calculator, input: &str) -> Result<Reply, CalcError> {
+    let input = input.trim();
+    
+    // 提取操作符
+    let mut chars = input.chars();
+    let operator = chars.next().unwrap();
+    let operand_str = chars.as_str().trim();
+    
+    // 检查输入格式
+    if operand_str.is_empty() {
+        return Err(CalcError::InvalidInput("输入格式错误，应该是: 操作符 数值 (例如: + 5)".to_string()));
+    }
+    
+    // 解析操作数（操作数本身也可以是表达式）
+    let expr = calculator::parse_expression(operand_str)?;
+    let operand = calculator.evaluate(&expr)?;
+    
+    // 执行计算
+    let result = calculator.calculate(operator, operand)?;
+    Ok(Reply::Result(result))
+}
+
+// Tab 补全：根据光标前的文本给出候选，候选是以光标前的单词开头的完整名称
+// 行首补全命令和名称，命令后面补全选项，其余位置补全变量、常量和函数（函数带左括号）
+pub fn completions(calculator: &Calculator, before: &str) -> Vec<String> {
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| !(ch.is_alphanumeric() || *ch == '_'))
+        .map_or(0, |(index, ch)| index + ch.len_utf8());
+    let (head, word) = before.split_at(start);
+    if word.is_empty() {
+        return Vec::new();
+    }
+
+    let options: &[&str] = match head.trim() {
+        "mode" => &["exact", "float", "programmer"],
+        "base" => &["hex", "bin", "oct", "dec"],
+        "word" => &["8", "16", "32", "64"],
+        "complex" => &["on", "off", "polar", "rect"],
+        "format" => &["auto", "fix", "sig", "sci", "eng", "sep", "fraction", "mixed", "decimal", "reset"],
+        "format sep" => &["on", "off"],
+        _ => &[],
+    };
+    let mut names: Vec<String> = options.iter().map(|option| option.to_string()).collect();
+    if names.is_empty() {
+        if head.trim().is_empty() {
+            names.extend(COMMAND_NAMES.iter().map(|name| name.to_string()));
+        }
+        let functions = calculator.functions().function_names().into_iter()
+            .chain(symbolic::SYMBOLIC_FUNCTIONS)
+            .chain(numeric::NUMERIC_FUNCTIONS)
+            .chain(statistics::STATISTICS_FUNCTIONS)
+            .chain(matrix::MATRIX_FUNCTIONS)
+            .chain(complex::COMPLEX_FUNCTIONS)
+            .chain(["if"]);
+        names.extend(functions.map(|name| format!("{}(", name)));
+        names.extend(calculator.function_definitions().into_iter().map(|function| format!("{}(", function.name)));
+        names.extend(calculator.variables().into_iter().map(|(name, _)| name.to_string()));
+        names.extend(calculator.functions().constant_names().into_iter().map(str::to_string));
+    }
+
+    names.retain(|name| name.starts_with(word));
+    names.sort();
+    names.dedup();
+    names
+}
+
+// 帮助信息
+pub fn help_lines(calculator: &Calculator) -> Vec<String> {
+    let mut lines = Vec::new();
+    lines.push("=== 帮助信息 ===".to_string());
+    lines.push("基本运算:".to_string());
+    lines.push("  + 数值    - 加法".to_string());
+    lines.push("  - 数值    - 减法".to_string());
+    lines.push("  * 数值    - 乘法".to_string());
+    lines.push("  / 数值    - 除法".to_string());
+    lines.push("  ^ 数值    - 幂运算".to_string());
+    lines.push("  (以运算符开头时对当前值运算，操作数可以是表达式；+ - 后需加空格)".to_string());
+    lines.push(String::new());
+    lines.push("表达式:".to_string());
+    lines.push("  3 + 4 * (2 - x) ^ 2   - 直接计算表达式，结果成为当前值".to_string());
+    lines.push("  支持括号、负号、^ 右结合，可以使用已存储的变量".to_string());
+    lines.push(String::new());
+    lines.push("数学函数 (例如: sqrt(2), log(2, 8), max(1, x, 3)):".to_string());
+    lines.push(format!("  {}", calculator.functions().function_names().join(", ")));
+    lines.push(format!("常量: {}", calculator.functions().constant_names().join(", ")));
+    lines.push(String::new());
+    lines.push("单位 (数字后面直接写单位，例如: 5 km + 300 m, 60 mph to m/s, 2 kB * 1024):".to_string());
+    lines.push("  长度 m in ft yd mi nmi, 体积 L, 质量 g t lb oz, 时间 s min h day week".to_string());
+    lines.push("  速度 mph kn, 数据 bit B, 温度 K degC degF; m g s L 可加 SI 前缀 (km, ms, mg)".to_string());
+    lines.push("  bit B 可加 k M G T 或 Ki Mi Gi Ti 前缀; 同名变量优先于单位".to_string());
+    lines.push("  表达式 to 单位     - 单位换算，例如 20 degC to degF".to_string());
+    lines.push(String::new());
+    lines.push("自定义函数:".to_string());
+    lines.push("  f(x, y) = x^2 + y       - 定义函数，同名函数会被重新定义".to_string());
+    lines.push("  f(3, 4)                 - 调用函数".to_string());
+    lines.push("  fact(n) = if(n, n * fact(n - 1), 1)".to_string());
+    lines.push("                          - if(条件, 真值, 假值) 条件非零时取真值，可用于递归".to_string());
+    lines.push(String::new());
+    lines.push("符号运算 (结果是表达式，不改变当前值):".to_string());
+    lines.push("  diff(x^2 * sin(x), x)   - 对 x 求导并化简".to_string());
+    lines.push("  simplify(2*x + 3*x - 1) - 合并同类项、折叠常数".to_string());
+    lines.push(String::new());
+    lines.push("列表和统计 (例如: data = [1, 4, 9, 16]):".to_string());
+    lines.push("  data * 2 + 1, sqrt(data) - 按元素计算，两个列表的长度必须相同".to_string());
+    lines.push(format!("  {}", statistics::STATISTICS_FUNCTIONS.join(", ")));
+    lines.push("  sum(data), min(data), max(data) - 参数是一个列表时计算列表的和、最小值、最大值".to_string());
+    lines.push("  percentile(data, 90)    - 第 90 百分位; linreg(xs, ys) 返回 [斜率, 截距]".to_string());
+    lines.push(String::new());
+    lines.push("复数 (例如: 3 + 4i, (1 + 2i) * (3 - i)):".to_string());
+    lines.push("  re, im, arg, conj, abs  - 实部、虚部、辐角、共轭、模长".to_string());
+    lines.push("  sqrt exp ln log10 sin cos tan 和 ^ 可以用于复数".to_string());
+    lines.push(String::new());
+    lines.push("矩阵和向量 (例如: A = [[1, 2], [3, 4]]):".to_string());
+    lines.push("  A * B, A * v, A^2       - 矩阵乘法和整数次幂；+ - 按元素计算，形状必须相同".to_string());
+    lines.push(format!("  {}", matrix::MATRIX_FUNCTIONS.join(", ")));
+    lines.push("  solve(A, b)             - 解线性方程组 A x = b".to_string());
+    lines.push(String::new());
+    lines.push("数值方法:".to_string());
+    lines.push("  solve(x^2 = 2, x, 1)    - 从初始值 1 开始求根（牛顿法，不收敛时改用二分法）".to_string());
+    lines.push("  solve(cos(x) = x, x, 0, 1) - 在区间 [0, 1] 内用二分法求根".to_string());
+    lines.push("  integrate(sin(x), x, 0, pi) - 自适应 Simpson 法求定积分".to_string());
+    lines.push("  sum(i^2, i, 1, 10)      - 变量取 1 到 10 的整数求和".to_string());
+    lines.push(String::new());
+    lines.push("变量操作:".to_string());
+    lines.push("  数值            - 设置当前值".to_string());
+    lines.push("  变量名 = 表达式 - 存储变量".to_string());
+    lines.push("  变量名          - 加载变量值".to_string());
+    lines.push(String::new());
+    lines.push("命令:".to_string());
+    lines.push("  help/h          - 显示帮助".to_string());
+    lines.push("  history         - 显示计算历史".to_string());
+    lines.push("  clear_history   - 清除历史".to_string());
+    lines.push("  undo / redo     - 撤销 / 重做上一次计算".to_string());
+    lines.push("  recall N        - 把第 N 条历史的结果载入为当前值".to_string());
+    lines.push("  replay A-B [值] - 从指定值（默认当前值）开始重新执行第 A 到 B 条历史".to_string());
+    lines.push("  variables/vars  - 显示所有变量".to_string());
+    lines.push("  functions/funcs - 显示自定义函数".to_string());
+    lines.push("  clear/c         - 重置计算器".to_string());
+    lines.push("  save 文件名     - 保存变量、函数和历史到文件".to_string());
+    lines.push("  load 文件名     - 从文件加载会话（替换当前状态）".to_string());
+    lines.push("  mode            - 显示当前计算模式".to_string());
+    lines.push("  mode exact      - 精确模式：大整数和分数运算，0.1 + 0.2 = 3/10".to_string());
+    lines.push("  mode float      - 浮点模式（默认）".to_string());
+    lines.push("  mode programmer - 整数模式：0xFF & 0x0F, 1 << 4 | 1, 6 xor 3, ~x，溢出时按字长回绕".to_string());
+    lines.push("  word 8|16|32|64 - 设置 programmer 模式的字长（默认 64）".to_string());
+    lines.push("  base hex|bin|oct|dec - programmer 模式下结果的显示进制".to_string());
+    lines.push("  complex on|off  - 开启后 sqrt(-1) = i，关闭时（默认）结果是复数的运算报错".to_string());
+    lines.push("  complex polar|rect - 复数按极坐标（模长∠辐角）或直角坐标（默认）显示".to_string());
+    lines.push("  format          - 显示当前的输出格式".to_string());
+    lines.push("  format fix 2 | sig 6 | sci 3 | eng 3 | auto".to_string());
+    lines.push("                  - 固定小数位数、有效数字、科学计数法、工程计数法、最短表示（默认）".to_string());
+    lines.push("  format sep on|off - 整数部分每三位加逗号".to_string());
+    lines.push("  format fraction|mixed|decimal - 精确的非整数显示为 7/3（默认）、2 1/3 或小数".to_string());
+    lines.push("  format reset    - 恢复默认的输出格式".to_string());
+    lines.push("  tolerance [值]  - 显示或设置数值方法的容差（默认 1e-10）".to_string());
+    lines.push("  iterations [N]  - 显示或设置求根的最大迭代次数（默认 100）".to_string());
+    lines.push("  quit/q/exit     - 退出程序".to_string());
+    lines.push("  # 注释          - # 之后的内容会被忽略".to_string());
+    lines.push(String::new());
+    lines.push("编辑: ← → 移动光标, ↑ ↓ 浏览输入历史, Ctrl-R 搜索输入历史, Tab 补全命令和名称".to_string());
+    lines.push("脚本模式: calculator -e 表达式 或 calculator 脚本文件 (详见 calculator --help)".to_string());
+    lines
+}
+
@@ -0,0 +1,281 @@
+// 复数 - 直角坐标和极坐标、复数的四则运算和初等函数
+// 学习目标：Copy 类型、运算方法、极坐标换算、用设置控制行为
+//
+// 复数的实部和虚部都是浮点数，精确模式下参与复数运算的值也会变成浮点数。
+// 运算结果的虚部正好为零时化为实数：i * i = -1。
+
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use crate::error::CalcError;
+use crate::number::Number;
+use crate::parser::BinaryOp;
+use crate::value::Value;
+
+// 虚数单位的名字，没有同名的变量时 2i、3 + 4i 中的 i 就是虚数单位
+pub const IMAGINARY_UNIT: &str = "i";
+
+// 只用于复数的函数，参数也可以是实数：re(3) = 3、arg(-1) = pi
+pub const COMPLEX_FUNCTIONS: [&str; 4] = ["re", "im", "arg", "conj"];
+
+// 有复数版本的内置函数，参数是复数（或开启复数运算后超出实数定义域）时使用
+const EXTENDED_FUNCTIONS: [&str; 8] = ["sqrt", "exp", "ln", "log10", "sin", "cos", "tan", "abs"];
+
+// 复数设置，可以用 complex 命令修改
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ComplexSettings {
+    pub enabled: bool, // 开启后 sqrt(-1)、ln(-1)、(-8)^(1/3) 得到复数，关闭时报错
+    pub polar: bool,   // 按极坐标显示：模长∠辐角（弧度）
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    pub fn from_polar(modulus: f64, argument: f64) -> Complex {
+        Complex::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    // 模长 |z|
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    // 辐角，范围是 (-pi, pi]
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn checked_div(self, other: Complex) -> Result<Complex, CalcError> {
+        let denominator = other.re * other.re + other.im * other.im;
+        if denominator == 0.0 {
+            return Err(CalcError::DivisionByZero);
+        }
+        let numerator = self * other.conj();
+        Ok(Complex::new(numerator.re / denominator, numerator.im / denominator))
+    }
+
+    // e^(a + bi) = e^a (cos b + i sin b)
+    pub fn exp(self) -> Complex {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    // 主值：ln|z| + i arg(z)
+    pub fn ln(self) -> Result<Complex, CalcError> {
+        if self.re == 0.0 && self.im == 0.0 {
+            return Err(CalcError::Math("ln 的参数不能为零".to_string()));
+        }
+        Ok(Complex::new(self.abs().ln(), self.arg()))
+    }
+
+    // 主平方根，实部不小于零；直接用代数公式计算，sqrt(-1) 正好是 i
+    pub fn sqrt(self) -> Complex {
+        let modulus = self.abs();
+        let re = ((modulus + self.re) / 2.0).sqrt();
+        let im = ((modulus - self.re) / 2.0).sqrt();
+        Complex::new(re, if self.im.is_sign_negative() { -im } else { im })
+    }
+
+    // 整数次幂用快速幂计算，避免 i^2 这样的结果带上舍入误差
+    pub fn powi(self, exponent: i64) -> Result<Complex, CalcError> {
+        let mut base = if exponent < 0 { Complex::new(1.0, 0.0).checked_div(self)? } else { self };
+        let mut result = Complex::new(1.0, 0.0);
+        let mut remaining = exponent.unsigned_abs();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            remaining >>= 1;
+        }
+        Ok(result)
+    }
+
+    // z^w = e^(w ln z)，取主值
+    pub fn pow(self, exponent: Complex) -> Result<Complex, CalcError> {
+        if exponent.im == 0.0 && exponent.re.fract() == 0.0 && exponent.re.abs() <= i64::MAX as f64 {
+            return self.powi(exponent.re as i64);
+        }
+        if exponent == Complex::new(0.5, 0.0) {
+            return Ok(self.sqrt());
+        }
+        if self.re == 0.0 && self.im == 0.0 {
+            return if exponent.re > 0.0 {
+                Ok(self)
+            } else {
+                Err(CalcError::Math(format!("0 的 {} 次幂没有定义", exponent)))
+            };
+        }
+        Ok((exponent * self.ln()?).exp())
+    }
+
+    // sin(a + bi) = sin a cosh b + i cos a sinh b
+    pub fn sin(self) -> Complex {
+        Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+
+    // cos(a + bi) = cos a cosh b - i sin a sinh b
+    pub fn cos(self) -> Complex {
+        Complex::new(self.re.cos() * self.im.cosh(), -self.re.sin() * self.im.sinh())
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+// (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+// 直角坐标显示：3 + 4i、-2i、1 - i
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_rect(*self, &|part| Number::Float(part).to_string()))
+    }
+}
+
+// 按直角坐标显示，number 决定实部和虚部的格式
+pub fn format_rect(z: Complex, number: &dyn Fn(f64) -> String) -> String {
+    let imaginary = |im: f64| {
+        if im == 1.0 {
+            IMAGINARY_UNIT.to_string()
+        } else {
+            format!("{}{}", number(im), IMAGINARY_UNIT)
+        }
+    };
+    if z.re == 0.0 {
+        let sign = if z.im < 0.0 { "-" } else { "" };
+        return format!("{}{}", sign, imaginary(z.im.abs()));
+    }
+    let sign = if z.im < 0.0 { "-" } else { "+" };
+    format!("{} {} {}", number(z.re), sign, imaginary(z.im.abs()))
+}
+
+// 极坐标显示：5∠0.9272952180016122
+pub fn format_polar(z: Complex, number: &dyn Fn(f64) -> String) -> String {
+    format!("{}∠{}", number(z.abs()), number(z.arg()))
+}
+
+// 复数或实数转换为复数，带单位的数值和列表不能参与复数运算
+pub fn to_complex(value: &Value) -> Result<Complex, CalcError> {
+    match value {
+        Value::Complex(z) => Ok(*z),
+        Value::Number(number) => Ok(Complex::new(number.to_f64(), 0.0)),
+        _ => Err(CalcError::Unit(format!("复数运算不支持带单位的数值或列表: {}", value))),
+    }
+}
+
+// 复数的二元运算
+pub fn apply(op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+    let (a, b) = (to_complex(lhs)?, to_complex(rhs)?);
+    let result = match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => a * b,
+        BinaryOp::Div => a.checked_div(b)?,
+        BinaryOp::Pow => a.pow(b)?,
+        _ => return Err(CalcError::Math(format!("位运算不能用于复数: {} 和 {}", lhs, rhs))),
+    };
+    finite(result)
+}
+
+// 实部或虚部溢出成 inf、NaN 时报错，而不是显示 NaN + NaNi：(1+i)^100000、exp(1000+i)
+fn finite(z: Complex) -> Result<Value, CalcError> {
+    if z.re.is_finite() && z.im.is_finite() {
+        Ok(Value::complex(z))
+    } else {
+        Err(CalcError::Overflow("复数运算的结果超出浮点数范围".to_string()))
+    }
+}
+
+// 是否按复数计算这个函数：参数中有复数，或者是 re、im、arg、conj
+pub fn is_complex_call(name: &str, args: &[Value]) -> bool {
+    COMPLEX_FUNCTIONS.contains(&name)
+        || (EXTENDED_FUNCTIONS.contains(&name) && args.iter().any(|arg| matches!(arg, Value::Complex(_))))
+}
+
+// 实数参数超出实数定义域、结果是复数的情况：sqrt(-1)、ln(-1)、log10(-10)
+pub fn needs_complex(name: &str, args: &[Number]) -> bool {
+    match (name, args) {
+        ("sqrt" | "ln" | "log10", [x]) => x.to_f64() < 0.0,
+        _ => false,
+    }
+}
+
+// 结果是复数时的错误提示
+pub fn not_real(name: &str, args: &[Number]) -> CalcError {
+    let args: Vec<String> = args.iter().map(Number::to_string).collect();
+    CalcError::NotReal(format!(
+        "{}({}) 的结果是复数，可以用 complex on 开启复数运算",
+        name,
+        args.join(", ")
+    ))
+}
+
+// 按复数计算函数
+pub fn call(name: &str, args: &[Value]) -> Result<Value, CalcError> {
+    let [arg] = args else {
+        return Err(CalcError::ArgumentCount {
+            name: name.to_string(),
+            expected: "1".to_string(),
+            actual: args.len(),
+        });
+    };
+    let z = to_complex(arg)?;
+    let real = |value: f64| Ok(Value::Number(Number::Float(value)));
+    let result = match name {
+        "re" => return real(z.re),
+        "im" => return real(z.im),
+        "arg" => {
+            if z.re == 0.0 && z.im == 0.0 {
+                return Err(CalcError::Math("0 的辐角没有定义".to_string()));
+            }
+            return real(z.arg());
+        },
+        "abs" => return real(z.abs()),
+        "conj" => z.conj(),
+        "sqrt" => z.sqrt(),
+        "exp" => z.exp(),
+        "ln" => z.ln()?,
+        "log10" => z.ln()?.checked_div(Complex::new(10f64.ln(), 0.0))?,
+        "sin" => z.sin(),
+        "cos" => z.cos(),
+        "tan" => z.sin().checked_div(z.cos())?,
+        _ => return Err(CalcError::UndefinedFunction(name.to_string())),
+    };
+    finite(result)
+}
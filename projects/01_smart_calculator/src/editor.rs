@@ -0,0 +1,543 @@
+// 行编辑器 - 交互模式的光标移动、输入历史、反向搜索和 Tab 补全
+// 学习目标：字节流解码、状态机、RAII（Drop 恢复终端设置）、调用外部命令
+//
+// 不依赖外部 crate：用 stty 把终端切换到非规范模式，自己处理每个按键。
+// 标准输入或标准输出不是终端时（管道、重定向）退回到逐行读取。
+// 支持的按键：
+//   ← → Home End Ctrl-A Ctrl-E     移动光标
+//   Backspace Delete Ctrl-U Ctrl-K  删除字符、删除到行首或行尾
+//   ↑ ↓                             浏览输入历史
+//   Ctrl-R                          反向搜索输入历史，再按一次找更早的匹配
+//   Tab                             补全命令、变量名和函数名
+//   Ctrl-C 放弃当前行，Ctrl-D 在空行上结束输入
+
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+// 输入历史文件名（位于用户主目录）
+const DEFAULT_FILE_NAME: &str = ".smart_calculator_history";
+
+// 可以用环境变量指定输入历史的位置，设置为空字符串时不保存输入历史
+const PATH_ENV_VAR: &str = "SMART_CALCULATOR_HISTORY";
+
+// 输入历史最多保留的行数，超出时丢弃最早的
+const MAX_HISTORY: usize = 1000;
+
+// 控制字符
+const CTRL_A: u8 = 0x01;
+const CTRL_C: u8 = 0x03;
+const CTRL_D: u8 = 0x04;
+const CTRL_E: u8 = 0x05;
+const CTRL_G: u8 = 0x07;
+const BACKSPACE: u8 = 0x08;
+const TAB: u8 = 0x09;
+const LINE_FEED: u8 = 0x0A;
+const CTRL_K: u8 = 0x0B;
+const CARRIAGE_RETURN: u8 = 0x0D;
+const CTRL_R: u8 = 0x12;
+const CTRL_U: u8 = 0x15;
+const ESCAPE: u8 = 0x1B;
+const DELETE: u8 = 0x7F;
+
+// 读取一行的结果
+pub enum ReadResult {
+    Line(String), // 用户按了回车
+    Interrupted,  // Ctrl-C 放弃了当前行
+    Eof,          // 输入结束（Ctrl-D 或管道关闭），光标还在提示符所在的行，由调用方换行
+}
+
+// 解码后的按键
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    Tab,
+    Control(u8),
+    Unknown,
+}
+
+// 正在进行的反向搜索
+struct Search {
+    query: String,
+    found: Option<usize>, // 匹配的历史下标，找到后编辑行显示这条历史
+    original: Vec<char>,  // 开始搜索前的编辑行，取消搜索时恢复
+}
+
+// 行编辑器：保存输入历史，逐行读取用户输入
+pub struct LineEditor {
+    history: Vec<String>,
+    path: Option<String>, // None 表示不保存输入历史
+}
+
+impl LineEditor {
+    // 从文件加载输入历史；文件不存在或无法读取时从空历史开始
+    pub fn new(path: Option<String>) -> Self {
+        let history = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| text.lines().filter(|line| !line.trim().is_empty()).map(str::to_string).collect())
+            .unwrap_or_default();
+        let mut editor = LineEditor { history, path };
+        editor.trim_history();
+        editor
+    }
+
+    // 把输入历史写回文件
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut text = self.history.join("\n");
+        text.push('\n');
+        fs::write(path, text)
+    }
+
+    // 记录一行输入；空行和与上一行相同的输入不记录
+    pub fn add_history(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() || self.history.last().is_some_and(|last| last == line) {
+            return;
+        }
+        self.history.push(line.to_string());
+        self.trim_history();
+    }
+
+    fn trim_history(&mut self) {
+        if self.history.len() > MAX_HISTORY {
+            self.history.drain(..self.history.len() - MAX_HISTORY);
+        }
+    }
+
+    // 显示提示符并读取一行；complete 根据光标前的文本给出补全候选
+    pub fn read_line(&mut self, prompt: &str, complete: &dyn Fn(&str) -> Vec<String>) -> io::Result<ReadResult> {
+        let mut stdout = io::stdout();
+        if !io::stdin().is_terminal() || !stdout.is_terminal() {
+            return read_plain_line(prompt);
+        }
+        let Some(_raw) = RawMode::enable() else {
+            return read_plain_line(prompt);
+        };
+
+        let mut session = EditSession {
+            prompt,
+            buffer: Vec::new(),
+            cursor: 0,
+            history_index: self.history.len(),
+            draft: Vec::new(),
+            search: None,
+        };
+        session.redraw(&mut stdout)?;
+
+        let mut input = io::stdin().lock();
+        loop {
+            let key = match read_key(&mut input)? {
+                Some(key) => key,
+                None => return Ok(ReadResult::Eof),
+            };
+
+            // 搜索中输入的字符修改搜索内容，其他按键结束搜索后按普通按键处理
+            if session.search.is_some() {
+                match key {
+                    Key::Char(ch) => {
+                        session.search_push(ch, &self.history);
+                        session.redraw(&mut stdout)?;
+                        continue;
+                    },
+                    Key::Backspace => {
+                        session.search_pop(&self.history);
+                        session.redraw(&mut stdout)?;
+                        continue;
+                    },
+                    Key::Control(CTRL_R) => {
+                        session.search_older(&self.history);
+                        session.redraw(&mut stdout)?;
+                        continue;
+                    },
+                    Key::Control(CTRL_C) | Key::Control(CTRL_G) => {
+                        session.cancel_search();
+                        session.redraw(&mut stdout)?;
+                        continue;
+                    },
+                    _ => session.accept_search(),
+                }
+            }
+
+            match key {
+                Key::Char(ch) => session.insert(ch),
+                Key::Enter => {
+                    session.redraw(&mut stdout)?;
+                    write!(stdout, "\r\n")?;
+                    stdout.flush()?;
+                    return Ok(ReadResult::Line(session.buffer.iter().collect()));
+                },
+                Key::Backspace => {
+                    if session.cursor > 0 {
+                        session.cursor -= 1;
+                        session.buffer.remove(session.cursor);
+                    }
+                },
+                Key::Delete => {
+                    if session.cursor < session.buffer.len() {
+                        session.buffer.remove(session.cursor);
+                    }
+                },
+                Key::Left => session.cursor = session.cursor.saturating_sub(1),
+                Key::Right => session.cursor = (session.cursor + 1).min(session.buffer.len()),
+                Key::Home | Key::Control(CTRL_A) => session.cursor = 0,
+                Key::End | Key::Control(CTRL_E) => session.cursor = session.buffer.len(),
+                Key::Up => session.history_step(&self.history, -1),
+                Key::Down => session.history_step(&self.history, 1),
+                Key::Tab => {
+                    let before: String = session.buffer[..session.cursor].iter().collect();
+                    let candidates = complete(&before);
+                    if let Some(listing) = session.complete(&candidates) {
+                        write!(stdout, "\r\n{}\r\n", listing)?;
+                    }
+                },
+                Key::Control(CTRL_K) => session.buffer.truncate(session.cursor),
+                Key::Control(CTRL_U) => {
+                    session.buffer.drain(..session.cursor);
+                    session.cursor = 0;
+                },
+                Key::Control(CTRL_R) => {
+                    session.search = Some(Search {
+                        query: String::new(),
+                        found: None,
+                        original: session.buffer.clone(),
+                    });
+                },
+                Key::Control(CTRL_C) => {
+                    write!(stdout, "^C\r\n")?;
+                    stdout.flush()?;
+                    return Ok(ReadResult::Interrupted);
+                },
+                Key::Control(CTRL_D) if session.buffer.is_empty() => return Ok(ReadResult::Eof),
+                Key::Control(CTRL_D) => {
+                    if session.cursor < session.buffer.len() {
+                        session.buffer.remove(session.cursor);
+                    }
+                },
+                Key::Control(_) | Key::Unknown => {},
+            }
+            session.redraw(&mut stdout)?;
+        }
+    }
+}
+
+// 输入历史文件的位置：环境变量优先，其次是用户主目录；返回 None 表示不保存输入历史
+pub fn default_history_path() -> Option<String> {
+    if let Ok(path) = env::var(PATH_ENV_VAR) {
+        return if path.is_empty() { None } else { Some(path) };
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let path: PathBuf = [home, DEFAULT_FILE_NAME.into()].iter().collect();
+    Some(path.to_string_lossy().into_owned())
+}
+
+// 不是终端时逐行读取，提示符同样立即显示
+fn read_plain_line(prompt: &str) -> io::Result<ReadResult> {
+    let mut stdout = io::stdout();
+    write!(stdout, "{}", prompt)?;
+    stdout.flush()?;
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Ok(ReadResult::Eof);
+    }
+    Ok(ReadResult::Line(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+// 终端的非规范模式，离开作用域时恢复原来的设置（包括出错提前返回的情况）
+struct RawMode {
+    saved: String, // stty -g 输出的原设置
+}
+
+impl RawMode {
+    fn enable() -> Option<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "-ixon", "-icrnl", "min", "1", "time", "0"])?;
+        Some(RawMode {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&[self.saved.as_str()]);
+    }
+}
+
+// 对当前终端执行 stty，成功时返回输出
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+fn read_byte(input: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+    loop {
+        match input.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+// ESC 后面的字节最多等待 0.1 秒：终端发送的转义序列是一起到达的，
+// 单独按 ESC 时没有后续字节，不能一直等到下一次按键
+fn read_byte_with_timeout(input: &mut impl Read) -> io::Result<Option<u8>> {
+    stty(&["min", "0", "time", "1"]);
+    let byte = read_byte(input);
+    stty(&["min", "1", "time", "0"]);
+    byte
+}
+
+// 读取一个按键：UTF-8 字符、控制字符或 ESC 开头的转义序列
+fn read_key(input: &mut impl Read) -> io::Result<Option<Key>> {
+    let Some(first) = read_byte(input)? else {
+        return Ok(None);
+    };
+    let key = match first {
+        CARRIAGE_RETURN | LINE_FEED => Key::Enter,
+        BACKSPACE | DELETE => Key::Backspace,
+        TAB => Key::Tab,
+        ESCAPE => read_escape(input)?,
+        0x00..=0x1F => Key::Control(first),
+        0x20..=0x7E => Key::Char(first as char),
+        _ => read_utf8(input, first)?,
+    };
+    Ok(Some(key))
+}
+
+// 多字节的 UTF-8 字符：首字节的高位 1 的个数就是总字节数
+fn read_utf8(input: &mut impl Read, first: u8) -> io::Result<Key> {
+    let length = first.leading_ones() as usize;
+    if !(2..=4).contains(&length) {
+        return Ok(Key::Unknown);
+    }
+    let mut bytes = vec![first];
+    for _ in 1..length {
+        match read_byte(input)? {
+            Some(byte) => bytes.push(byte),
+            None => return Ok(Key::Unknown),
+        }
+    }
+    Ok(match std::str::from_utf8(&bytes).ok().and_then(|text| text.chars().next()) {
+        Some(ch) => Key::Char(ch),
+        None => Key::Unknown,
+    })
+}
+
+// 方向键等功能键：ESC [ A、ESC [ 3 ~、ESC O H 等；单独按 ESC 时什么也不做
+fn read_escape(input: &mut impl Read) -> io::Result<Key> {
+    let Some(kind) = read_byte_with_timeout(input)? else {
+        return Ok(Key::Unknown);
+    };
+    if kind != b'[' && kind != b'O' {
+        return Ok(Key::Unknown);
+    }
+
+    // 参数是数字和分号，以字母或 ~ 结束
+    let mut params = String::new();
+    loop {
+        let Some(byte) = read_byte(input)? else {
+            return Ok(Key::Unknown);
+        };
+        if byte.is_ascii_digit() || byte == b';' {
+            params.push(byte as char);
+            continue;
+        }
+        return Ok(match (byte, params.as_str()) {
+            (b'A', _) => Key::Up,
+            (b'B', _) => Key::Down,
+            (b'C', _) => Key::Right,
+            (b'D', _) => Key::Left,
+            (b'H', _) | (b'~', "1") | (b'~', "7") => Key::Home,
+            (b'F', _) | (b'~', "4") | (b'~', "8") => Key::End,
+            (b'~', "3") => Key::Delete,
+            _ => Key::Unknown,
+        });
+    }
+}
+
+// 正在编辑的一行
+struct EditSession<'a> {
+    prompt: &'a str,
+    buffer: Vec<char>,
+    cursor: usize,        // 光标位置（字符下标）
+    history_index: usize, // 正在浏览的历史下标，等于历史长度时表示正在编辑的新行
+    draft: Vec<char>,     // 开始浏览历史前正在编辑的内容
+    search: Option<Search>,
+}
+
+impl EditSession<'_> {
+    fn insert(&mut self, ch: char) {
+        self.buffer.insert(self.cursor, ch);
+        self.cursor += 1;
+    }
+
+    fn set_buffer(&mut self, text: &str) {
+        self.buffer = text.chars().collect();
+        self.cursor = self.buffer.len();
+    }
+
+    // 上一条（step = -1）或下一条（step = 1）历史
+    fn history_step(&mut self, history: &[String], step: isize) {
+        let Some(index) = self.history_index.checked_add_signed(step) else {
+            return;
+        };
+        if index > history.len() {
+            return;
+        }
+        if self.history_index == history.len() {
+            self.draft = self.buffer.clone();
+        }
+        self.history_index = index;
+        if index == history.len() {
+            let draft: String = self.draft.iter().collect();
+            self.set_buffer(&draft);
+        } else {
+            self.set_buffer(&history[index]);
+        }
+    }
+
+    // 从 before 处（不含）往前找包含搜索内容的历史，找到时显示在编辑行中；找不到时保持原来的匹配
+    fn search_from(&mut self, history: &[String], before: usize) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        let before = before.min(history.len());
+        if let Some(index) = (0..before).rev().find(|&index| history[index].contains(&search.query)) {
+            search.found = Some(index);
+            self.set_buffer(&history[index]);
+        }
+    }
+
+    fn search_push(&mut self, ch: char, history: &[String]) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.query.push(ch);
+        // 从当前匹配开始找，当前匹配仍然包含新的搜索内容时保持不动
+        let start = search.found.map_or(history.len(), |index| index + 1);
+        search.found = None;
+        self.search_from(history, start);
+    }
+
+    fn search_pop(&mut self, history: &[String]) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.query.pop();
+        search.found = None;
+        self.search_from(history, history.len());
+    }
+
+    // 再按一次 Ctrl-R：找更早的匹配
+    fn search_older(&mut self, history: &[String]) {
+        let before = self.search.as_ref().and_then(|search| search.found).unwrap_or(history.len());
+        self.search_from(history, before);
+    }
+
+    // 结束搜索，匹配的历史留在编辑行中继续编辑
+    fn accept_search(&mut self) {
+        if let Some(Search { found: Some(index), .. }) = self.search.take() {
+            self.history_index = index;
+        }
+    }
+
+    // 取消搜索，恢复搜索前的编辑行
+    fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            self.buffer = search.original;
+            self.cursor = self.buffer.len();
+        }
+    }
+
+    // 补全光标前的单词；候选不止一个且没有公共前缀可以补全时返回候选列表
+    fn complete(&mut self, candidates: &[String]) -> Option<String> {
+        let start = word_start(&self.buffer[..self.cursor]);
+        let word: String = self.buffer[start..self.cursor].iter().collect();
+        let common = common_prefix(candidates)?;
+        if common.chars().count() > word.chars().count() {
+            for ch in common.chars().skip(word.chars().count()) {
+                self.insert(ch);
+            }
+            return None;
+        }
+        if candidates.len() > 1 {
+            return Some(candidates.join("  "));
+        }
+        None
+    }
+
+    // 回到行首重新显示提示符和内容，再把光标移到正确的位置
+    fn redraw(&self, stdout: &mut impl Write) -> io::Result<()> {
+        let line: String = self.buffer.iter().collect();
+        match &self.search {
+            Some(search) => {
+                let matched = search.found.map_or("", |_| line.as_str());
+                write!(stdout, "\r(反向搜索 '{}'): {}\x1b[K", search.query, matched)?;
+            },
+            None => {
+                write!(stdout, "\r{}{}\x1b[K", self.prompt, line)?;
+                let width: usize = self.buffer[self.cursor..].iter().map(|&ch| char_width(ch)).sum();
+                if width > 0 {
+                    write!(stdout, "\x1b[{}D", width)?;
+                }
+            },
+        }
+        stdout.flush()
+    }
+}
+
+// 光标前单词的开始位置，单词由字母、数字和下划线组成
+fn word_start(before: &[char]) -> usize {
+    before
+        .iter()
+        .rposition(|ch| !(ch.is_alphanumeric() || *ch == '_'))
+        .map_or(0, |index| index + 1)
+}
+
+// 所有候选的最长公共前缀，没有候选时返回 None
+fn common_prefix(candidates: &[String]) -> Option<String> {
+    let first = candidates.first()?;
+    let mut prefix: Vec<char> = first.chars().collect();
+    for candidate in &candidates[1..] {
+        let matching = prefix.iter().zip(candidate.chars()).take_while(|(a, b)| **a == *b).count();
+        prefix.truncate(matching);
+    }
+    Some(prefix.into_iter().collect())
+}
+
+// 字符在终端中占的列数：中日韩文字和全角符号占 2 列
+fn char_width(ch: char) -> usize {
+    match ch as u32 {
+        0x1100..=0x115F | 0x2E80..=0x303E | 0x3041..=0x33FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF | 0xFE30..=0xFE4F | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 | 0x1F300..=0x1F64F | 0x1F900..=0x1F9FF | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,107 @@
+// 错误类型 - 计算器所有操作共用的错误枚举
+// 学习目标：枚举携带数据、Display 和 Error trait、Box 递归
+
+use std::error::Error;
+use std::fmt;
+
+use crate::parser::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcError {
+    // 表达式语法错误，保留原始输入用于指出出错位置
+    Parse { error: ParseError, input: String },
+    DivisionByZero,
+    UndefinedVariable(String),
+    UndefinedFunction(String),
+    // 参数个数不对，expected 例如 "2" 或 "至少 1"
+    ArgumentCount { name: String, expected: String, actual: usize },
+    // 参数超出定义域、结果无定义、操作数不是整数等
+    Math(String),
+    // 结果是复数，但没有开启复数运算，例如 sqrt(-1)
+    NotReal(String),
+    // 结果太大，无法精确表示
+    Overflow(String),
+    // 量纲不同的数值相加减，operation 是 "相加" 或 "相减"
+    DimensionMismatch { operation: &'static str, lhs: String, rhs: String },
+    // 列表、矩阵的形状不匹配，lhs 和 rhs 是形状的描述，例如 "2×3 矩阵"
+    ShapeMismatch { operation: &'static str, lhs: String, rhs: String },
+    // 其他单位错误：未知单位、无法换算、带单位的数值不支持的运算
+    Unit(String),
+    RecursionLimit { name: String, limit: usize },
+    // 用户函数内部出错，只标注最内层的函数
+    InFunction { name: String, error: Box<CalcError> },
+    // 无效的历史编号或范围
+    History(String),
+    // 会话文件读写或格式错误
+    Session(String),
+    // 命令或定义的格式错误、名称无效等
+    InvalidInput(String),
+}
+
+impl CalcError {
+    // 标注出错的用户函数；已经标注过时保持不变
+    pub fn in_function(self, name: &str) -> CalcError {
+        match self {
+            CalcError::InFunction { .. } => self,
+            error => CalcError::InFunction {
+                name: name.to_string(),
+                error: Box::new(error),
+            },
+        }
+    }
+
+    // 错误的种类，供 --serve 模式的调用方按种类处理；函数内部的错误取最内层的种类
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CalcError::Parse { .. } => "parse",
+            CalcError::DivisionByZero => "division_by_zero",
+            CalcError::UndefinedVariable(_) => "undefined_variable",
+            CalcError::UndefinedFunction(_) => "undefined_function",
+            CalcError::ArgumentCount { .. } => "argument_count",
+            CalcError::Math(_) => "math",
+            CalcError::NotReal(_) => "not_real",
+            CalcError::Overflow(_) => "overflow",
+            CalcError::DimensionMismatch { .. } => "dimension_mismatch",
+            CalcError::ShapeMismatch { .. } => "shape_mismatch",
+            CalcError::Unit(_) => "unit",
+            CalcError::RecursionLimit { .. } => "recursion_limit",
+            CalcError::InFunction { error, .. } => error.kind(),
+            CalcError::History(_) => "history",
+            CalcError::Session(_) => "session",
+            CalcError::InvalidInput(_) => "invalid_input",
+        }
+    }
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::Parse { error, input } => write!(f, "{}\n{}", error, error.pointer(input)),
+            CalcError::DivisionByZero => write!(f, "除零操作"),
+            CalcError::UndefinedVariable(name) => write!(f, "未定义的变量: {}", name),
+            CalcError::UndefinedFunction(name) => write!(f, "未定义的函数: {}", name),
+            CalcError::ArgumentCount { name, expected, actual } => {
+                write!(f, "函数 {} 需要 {} 个参数，实际传入 {} 个", name, expected, actual)
+            },
+            CalcError::DimensionMismatch { operation, lhs, rhs } => {
+                write!(f, "不能把 {} 和 {} {}", lhs, rhs, operation)
+            },
+            CalcError::ShapeMismatch { operation, lhs, rhs } => {
+                write!(f, "形状不匹配，不能把 {} 和 {} {}", lhs, rhs, operation)
+            },
+            CalcError::RecursionLimit { name, limit } => {
+                write!(f, "超过最大递归深度 {}，函数 {} 可能无限递归", limit, name)
+            },
+            CalcError::InFunction { name, error } => write!(f, "{} (在函数 {} 中)", error, name),
+            CalcError::Math(message)
+            | CalcError::Overflow(message)
+            | CalcError::NotReal(message)
+            | CalcError::Unit(message)
+            | CalcError::History(message)
+            | CalcError::Session(message)
+            | CalcError::InvalidInput(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for CalcError {}
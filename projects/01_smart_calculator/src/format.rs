@@ -0,0 +1,274 @@
+// 输出格式 - 定点小数、有效数字、科学计数法、工程计数法、千位分隔符和分数显示
+// 学习目标：枚举携带参数、用大整数做精确的十进制舍入、字符串拼接
+//
+// 舍入在有理数上进行（四舍五入，.5 远离零），浮点数先按最短十进制表示转换，
+// 因此 format fix 20 下 0.1 显示为 0.10000000000000000000 而不是二进制误差。
+// 精确的非整数按分数显示（可以选择带分数或小数），整数和浮点数按选择的记数法显示。
+
+use crate::bigint::BigInt;
+use crate::number::Number;
+use crate::rational::Rational;
+
+// 小数位数和有效数字的上限
+pub const MAX_DIGITS: u32 = 100;
+
+// 有效数字记数法中按普通小数显示的指数范围，超出时改用科学计数法
+const POSITIONAL_EXPONENTS: std::ops::Range<i64> = -5..15;
+
+// 记数法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Notation {
+    Auto,             // 最短的能精确还原的表示（默认）
+    Fixed(u32),       // 固定小数位数：fix 2 -> 3.14
+    Significant(u32), // 有效数字：sig 3 -> 0.00123、1230
+    Scientific(u32),  // 科学计数法，参数是有效数字：sci 3 -> 1.23e4
+    Engineering(u32), // 工程计数法，指数是 3 的倍数：eng 3 -> 12.3e3
+}
+
+impl Notation {
+    pub fn name(self) -> String {
+        match self {
+            Notation::Auto => "auto".to_string(),
+            Notation::Fixed(digits) => format!("fix {}", digits),
+            Notation::Significant(digits) => format!("sig {}", digits),
+            Notation::Scientific(digits) => format!("sci {}", digits),
+            Notation::Engineering(digits) => format!("eng {}", digits),
+        }
+    }
+
+    // 解析 "auto"、"fix 2"、"sig 6" 之类的文本；小数位数可以是 0，有效数字至少是 1
+    pub fn parse(text: &str) -> Option<Notation> {
+        let mut parts = text.split_whitespace();
+        let kind = parts.next()?;
+        if kind == "auto" {
+            return parts.next().is_none().then_some(Notation::Auto);
+        }
+        let digits: u32 = parts.next()?.parse().ok().filter(|digits| *digits <= MAX_DIGITS)?;
+        if parts.next().is_some() || (kind != "fix" && digits == 0) {
+            return None;
+        }
+        match kind {
+            "fix" => Some(Notation::Fixed(digits)),
+            "sig" => Some(Notation::Significant(digits)),
+            "sci" => Some(Notation::Scientific(digits)),
+            "eng" => Some(Notation::Engineering(digits)),
+            _ => None,
+        }
+    }
+}
+
+// 精确的非整数的显示方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FractionStyle {
+    Fraction, // 假分数：7/3（默认）
+    Mixed,    // 带分数：2 1/3
+    Decimal,  // 按记数法显示为小数：2.333…
+}
+
+impl FractionStyle {
+    pub fn name(self) -> &'static str {
+        match self {
+            FractionStyle::Fraction => "fraction",
+            FractionStyle::Mixed => "mixed",
+            FractionStyle::Decimal => "decimal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<FractionStyle> {
+        match name {
+            "fraction" => Some(FractionStyle::Fraction),
+            "mixed" => Some(FractionStyle::Mixed),
+            "decimal" => Some(FractionStyle::Decimal),
+            _ => None,
+        }
+    }
+}
+
+// 输出格式设置，可以用 format 命令修改
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormatSettings {
+    pub notation: Notation,
+    pub separators: bool, // 整数部分每三位加逗号：1,234,567.5
+    pub fractions: FractionStyle,
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        FormatSettings {
+            notation: Notation::Auto,
+            separators: false,
+            fractions: FractionStyle::Fraction,
+        }
+    }
+}
+
+impl FormatSettings {
+    // 显示一个数值
+    pub fn format_number(&self, number: &Number) -> String {
+        match number {
+            Number::Float(value) => self.format_float(*value),
+            Number::Exact(value) if !value.is_integer() && self.fractions != FractionStyle::Decimal => {
+                self.format_fraction(value)
+            },
+            Number::Exact(value) => self.format_decimal(value),
+        }
+    }
+
+    // 显示一个浮点数，复数的实部和虚部也用这个方法显示
+    pub fn format_float(&self, value: f64) -> String {
+        if self.notation == Notation::Auto {
+            return self.group(&value.to_string());
+        }
+        match Rational::from_f64(value) {
+            Some(exact) => self.format_decimal(&exact),
+            None => value.to_string(), // NaN 和无穷大
+        }
+    }
+
+    // 按记数法把有理数显示为小数，auto 时非整数显示为最接近的浮点数
+    pub fn format_decimal(&self, value: &Rational) -> String {
+        match self.notation {
+            Notation::Auto if value.is_integer() => self.group(&value.numer().to_string()),
+            Notation::Auto => self.group(&value.to_f64().to_string()),
+            Notation::Fixed(digits) => self.fixed(value, digits),
+            Notation::Significant(digits) => self.significant(value, digits),
+            Notation::Scientific(digits) => scientific(value, digits, 1),
+            Notation::Engineering(digits) => scientific(value, digits, 3),
+        }
+    }
+
+    // 分数或带分数，分子分母也加千位分隔符
+    fn format_fraction(&self, value: &Rational) -> String {
+        let sign = if value.is_negative() { "-" } else { "" };
+        let magnitude = value.abs();
+        let (numer, denom) = (magnitude.numer(), magnitude.denom());
+        if self.fractions == FractionStyle::Mixed {
+            let (whole, remainder) = numer.div_rem(denom).expect("分母不为零");
+            if !whole.is_zero() {
+                return format!(
+                    "{}{} {}/{}",
+                    sign,
+                    self.group(&whole.to_string()),
+                    self.group(&remainder.to_string()),
+                    self.group(&denom.to_string())
+                );
+            }
+        }
+        format!("{}{}/{}", sign, self.group(&numer.to_string()), self.group(&denom.to_string()))
+    }
+
+    // 固定小数位数
+    fn fixed(&self, value: &Rational, digits: u32) -> String {
+        let scaled = value.abs().mul(&power_of_ten(digits as i64)).round();
+        let mut text = scaled.to_string();
+        if text.len() <= digits as usize {
+            text = format!("{}{}", "0".repeat(digits as usize + 1 - text.len()), text);
+        }
+        let (integer, fraction) = text.split_at(text.len() - digits as usize);
+        let sign = if value.is_negative() && !scaled.is_zero() { "-" } else { "" };
+        if fraction.is_empty() {
+            format!("{}{}", sign, self.group(integer))
+        } else {
+            format!("{}{}.{}", sign, self.group(integer), fraction)
+        }
+    }
+
+    // 有效数字；指数太大或太小时改用科学计数法
+    fn significant(&self, value: &Rational, digits: u32) -> String {
+        if value.is_zero() {
+            return "0".to_string();
+        }
+        let (mantissa, exponent) = round_significant(value, digits);
+        if !POSITIONAL_EXPONENTS.contains(&exponent) {
+            return scientific(value, digits, 1);
+        }
+        let sign = if value.is_negative() { "-" } else { "" };
+        let point = exponent + 1; // 小数点前的位数
+        let text = if point <= 0 {
+            format!("0.{}{}", "0".repeat(-point as usize), mantissa)
+        } else if point as usize >= mantissa.len() {
+            format!("{}{}", mantissa, "0".repeat(point as usize - mantissa.len()))
+        } else {
+            format!("{}.{}", &mantissa[..point as usize], &mantissa[point as usize..])
+        };
+        format!("{}{}", sign, self.group(&text))
+    }
+
+    // 给整数部分加千位分隔符，text 可以带负号和小数部分
+    fn group(&self, text: &str) -> String {
+        if !self.separators {
+            return text.to_string();
+        }
+        let (sign, rest) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text),
+        };
+        let split = rest.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(rest.len());
+        let (integer, tail) = rest.split_at(split);
+        let mut grouped = String::new();
+        for (index, ch) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(ch);
+        }
+        format!("{}{}{}", sign, grouped, tail)
+    }
+}
+
+// 科学计数法（step = 1）或工程计数法（step = 3）：尾数保留 digits 位有效数字
+fn scientific(value: &Rational, digits: u32, step: i64) -> String {
+    let (mut mantissa, exponent) = if value.is_zero() {
+        ("0".repeat(digits as usize), 0)
+    } else {
+        round_significant(value, digits)
+    };
+    let shown = exponent - exponent.rem_euclid(step);
+    let point = (exponent - shown + 1) as usize; // 尾数小数点前的位数
+    if mantissa.len() < point {
+        mantissa.push_str(&"0".repeat(point - mantissa.len()));
+    }
+    let sign = if value.is_negative() { "-" } else { "" };
+    let (integer, fraction) = mantissa.split_at(point);
+    if fraction.is_empty() {
+        format!("{}{}e{}", sign, integer, shown)
+    } else {
+        format!("{}{}.{}e{}", sign, integer, fraction, shown)
+    }
+}
+
+// 四舍五入到 digits 位有效数字，返回数字串和最高位的十进制指数：1234 -> ("123", 3)
+fn round_significant(value: &Rational, digits: u32) -> (String, i64) {
+    let magnitude = value.abs();
+    let mut exponent = decimal_exponent(&magnitude);
+    loop {
+        let scale = power_of_ten(digits as i64 - 1 - exponent);
+        let rounded = magnitude.mul(&scale).round().to_string();
+        // 进位后多出一位（9.99 -> 10.0），指数加一重新舍入
+        if rounded.len() > digits as usize {
+            exponent += 1;
+            continue;
+        }
+        return (rounded, exponent);
+    }
+}
+
+// 正有理数的十进制指数：10^e <= value < 10^(e+1)
+fn decimal_exponent(value: &Rational) -> i64 {
+    let estimate = value.numer().to_string().len() as i64 - value.denom().to_string().len() as i64;
+    if *value >= power_of_ten(estimate) {
+        estimate
+    } else {
+        estimate - 1
+    }
+}
+
+// 10 的整数次幂，指数可以是负数
+fn power_of_ten(exponent: i64) -> Rational {
+    let power = BigInt::from_u64(10).pow(exponent.unsigned_abs() as u32);
+    if exponent >= 0 {
+        Rational::from_integer(power)
+    } else {
+        Rational::new(BigInt::one(), power).expect("分母不为零")
+    }
+}
@@ -0,0 +1,316 @@
+// 数学函数库 - 内置函数、常量和可扩展的函数注册表
+// 学习目标：闭包、trait 对象（Box<dyn Fn>）、HashMap、错误处理
+
+use std::collections::HashMap;
+use std::f64::consts;
+
+use crate::bigint::BigInt;
+use crate::error::CalcError;
+use crate::number::Number;
+use crate::rational::Rational;
+
+// 精确阶乘允许的最大参数，更大的值改用浮点数计算
+const MAX_EXACT_FACTORIAL: i64 = 10_000;
+
+// 原生函数：接收已经求值的参数，返回结果或错误
+pub type NativeFn = Box<dyn Fn(&[f64]) -> Result<f64, CalcError> + Send + Sync>;
+
+// 能够保持精确结果的函数，直接处理 Number
+type ExactFn = Box<dyn Fn(&[Number]) -> Result<Number, CalcError> + Send + Sync>;
+
+// 函数体：浮点函数的参数会先转换成 f64，结果总是浮点数
+enum FunctionBody {
+    Float(NativeFn),
+    Exact(ExactFn),
+}
+
+// 函数接受的参数个数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arity {
+    Exact(usize),
+    AtLeast(usize),
+}
+
+impl Arity {
+    fn accepts(self, count: usize) -> bool {
+        match self {
+            Arity::Exact(n) => count == n,
+            Arity::AtLeast(n) => count >= n,
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Arity::Exact(n) => n.to_string(),
+            Arity::AtLeast(n) => format!("至少 {}", n),
+        }
+    }
+}
+
+struct Function {
+    arity: Arity,
+    body: FunctionBody,
+}
+
+// 函数注册表（练习 HashMap 存放闭包）
+pub struct FunctionRegistry {
+    functions: HashMap<String, Function>,
+    constants: HashMap<String, f64>,
+}
+
+impl FunctionRegistry {
+    // 创建空的注册表
+    pub fn new() -> Self {
+        FunctionRegistry {
+            functions: HashMap::new(),
+            constants: HashMap::new(),
+        }
+    }
+
+    // 创建带有全部内置函数和常量的注册表
+    pub fn with_builtins() -> Self {
+        let mut registry = FunctionRegistry::new();
+        registry.register_builtins();
+        registry
+    }
+
+    // 注册函数，同名函数会被覆盖
+    pub fn register<F>(&mut self, name: &str, arity: Arity, body: F)
+    where
+        F: Fn(&[f64]) -> Result<f64, CalcError> + Send + Sync + 'static,
+    {
+        let function = Function {
+            arity,
+            body: FunctionBody::Float(Box::new(body)),
+        };
+        self.functions.insert(name.to_string(), function);
+    }
+    
+    // 注册能保持精确结果的函数
+    fn register_exact<F>(&mut self, name: &str, arity: Arity, body: F)
+    where
+        F: Fn(&[Number]) -> Result<Number, CalcError> + Send + Sync + 'static,
+    {
+        let function = Function {
+            arity,
+            body: FunctionBody::Exact(Box::new(body)),
+        };
+        self.functions.insert(name.to_string(), function);
+    }
+
+    // 注册常量
+    pub fn register_constant(&mut self, name: &str, value: f64) {
+        self.constants.insert(name.to_string(), value);
+    }
+
+    // 查找常量，常量都是无理数，因此总是浮点数
+    pub fn constant(&self, name: &str) -> Option<Number> {
+        self.constants.get(name).copied().map(Number::Float)
+    }
+
+    // 调用函数，先检查参数个数
+    pub fn call(&self, name: &str, args: &[Number]) -> Result<Number, CalcError> {
+        let function = self
+            .functions
+            .get(name)
+            .ok_or_else(|| CalcError::UndefinedFunction(name.to_string()))?;
+
+        if !function.arity.accepts(args.len()) {
+            return Err(CalcError::ArgumentCount {
+                name: name.to_string(),
+                expected: function.arity.describe(),
+                actual: args.len(),
+            });
+        }
+
+        let result = match &function.body {
+            FunctionBody::Float(body) => {
+                let args: Vec<f64> = args.iter().map(Number::to_f64).collect();
+                Number::Float(body(&args)?)
+            },
+            FunctionBody::Exact(body) => body(args)?,
+        };
+        if let Number::Float(value) = result {
+            if value.is_nan() {
+                return Err(CalcError::Math(format!("函数 {} 的结果无定义", name)));
+            }
+        }
+        Ok(result)
+    }
+
+    // 按字母顺序列出所有函数名
+    pub fn function_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.functions.keys().map(|name| name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    // 按字母顺序列出所有常量名
+    pub fn constant_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.constants.keys().map(|name| name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    fn register_builtins(&mut self) {
+        // 常量
+        self.register_constant("pi", consts::PI);
+        self.register_constant("e", consts::E);
+        self.register_constant("tau", consts::TAU);
+
+        // 幂和对数
+        self.register_exact("sqrt", Arity::Exact(1), |args| {
+            if args[0].compare(&Number::zero()).is_lt() {
+                return Err(CalcError::Math("sqrt 的参数不能为负数".to_string()));
+            }
+            // 完全平方数保持精确：sqrt(9/4) = 3/2
+            if let Number::Exact(value) = &args[0] {
+                if let Some(root) = value.sqrt_exact() {
+                    return Ok(Number::Exact(root));
+                }
+            }
+            Ok(Number::Float(args[0].to_f64().sqrt()))
+        });
+        self.register("exp", Arity::Exact(1), |args| Ok(args[0].exp()));
+        self.register("ln", Arity::Exact(1), |args| {
+            require_positive("ln", args[0])?;
+            Ok(args[0].ln())
+        });
+        self.register("log10", Arity::Exact(1), |args| {
+            require_positive("log10", args[0])?;
+            Ok(args[0].log10())
+        });
+        self.register("log", Arity::Exact(2), |args| {
+            let (base, x) = (args[0], args[1]);
+            if base <= 0.0 || base == 1.0 {
+                return Err(CalcError::Math("log 的底数必须为正数且不等于 1".to_string()));
+            }
+            require_positive("log", x)?;
+            Ok(x.ln() / base.ln())
+        });
+
+        // 三角函数和反三角函数
+        self.register("sin", Arity::Exact(1), |args| Ok(args[0].sin()));
+        self.register("cos", Arity::Exact(1), |args| Ok(args[0].cos()));
+        self.register("tan", Arity::Exact(1), |args| Ok(args[0].tan()));
+        self.register("asin", Arity::Exact(1), |args| {
+            require_unit_interval("asin", args[0])?;
+            Ok(args[0].asin())
+        });
+        self.register("acos", Arity::Exact(1), |args| {
+            require_unit_interval("acos", args[0])?;
+            Ok(args[0].acos())
+        });
+        self.register("atan", Arity::Exact(1), |args| Ok(args[0].atan()));
+        self.register("atan2", Arity::Exact(2), |args| Ok(args[0].atan2(args[1])));
+
+        // 取整和绝对值
+        self.register_exact("abs", Arity::Exact(1), |args| Ok(args[0].abs()));
+        self.register_exact("floor", Arity::Exact(1), |args| {
+            Ok(round_with(&args[0], f64::floor, Rational::floor))
+        });
+        self.register_exact("ceil", Arity::Exact(1), |args| {
+            Ok(round_with(&args[0], f64::ceil, Rational::ceil))
+        });
+        self.register_exact("round", Arity::Exact(1), |args| {
+            Ok(round_with(&args[0], f64::round, Rational::round))
+        });
+
+        // 可变参数函数
+        self.register_exact("min", Arity::AtLeast(1), |args| {
+            Ok(args.iter().min_by(|a, b| a.compare(b)).cloned().expect("至少一个参数"))
+        });
+        self.register_exact("max", Arity::AtLeast(1), |args| {
+            Ok(args.iter().max_by(|a, b| a.compare(b)).cloned().expect("至少一个参数"))
+        });
+
+        // 整数函数：参数是精确整数时结果也是精确整数
+        self.register_exact("factorial", Arity::Exact(1), |args| {
+            let n = require_integer("factorial", &args[0])?;
+            if n.is_negative() {
+                return Err(CalcError::Math("factorial 的参数不能为负数".to_string()));
+            }
+            let n = n.to_i64().unwrap_or(i64::MAX);
+            if args[0].is_exact() && n <= MAX_EXACT_FACTORIAL {
+                let mut result = BigInt::one();
+                for k in 2..=n {
+                    result = &result * &BigInt::from_i64(k);
+                }
+                return Ok(Number::Exact(Rational::from_integer(result)));
+            }
+            let result = (1..=n.min(171)).fold(1.0, |acc, k| acc * k as f64);
+            if result.is_infinite() {
+                return Err(CalcError::Overflow("factorial 的结果溢出".to_string()));
+            }
+            Ok(Number::Float(result))
+        });
+        self.register_exact("gcd", Arity::AtLeast(2), |args| {
+            let mut result = BigInt::zero();
+            for arg in args {
+                result = result.gcd(&require_integer("gcd", arg)?);
+            }
+            Ok(integer_result(result, args))
+        });
+        self.register_exact("lcm", Arity::AtLeast(2), |args| {
+            let mut result = BigInt::one();
+            for arg in args {
+                let n = require_integer("lcm", arg)?.abs();
+                if n.is_zero() {
+                    return Ok(integer_result(BigInt::zero(), args));
+                }
+                let (reduced, _) = result.div_rem(&result.gcd(&n)).expect("除数不为零");
+                result = &reduced * &n;
+            }
+            Ok(integer_result(result, args))
+        });
+    }
+}
+
+impl Default for FunctionRegistry {
+    fn default() -> Self {
+        FunctionRegistry::new()
+    }
+}
+
+fn require_positive(name: &str, x: f64) -> Result<(), CalcError> {
+    if x <= 0.0 {
+        return Err(CalcError::Math(format!("{} 的参数必须为正数", name)));
+    }
+    Ok(())
+}
+
+fn require_unit_interval(name: &str, x: f64) -> Result<(), CalcError> {
+    if !(-1.0..=1.0).contains(&x) {
+        return Err(CalcError::Math(format!("{} 的参数必须在 [-1, 1] 范围内", name)));
+    }
+    Ok(())
+}
+
+// 检查参数是整数，并转换为 BigInt
+fn require_integer(name: &str, x: &Number) -> Result<BigInt, CalcError> {
+    match x {
+        Number::Exact(value) if value.is_integer() => Ok(value.numer().clone()),
+        Number::Float(value) if value.fract() == 0.0 => {
+            Ok(BigInt::parse(&format!("{:.0}", value)).expect("整数的十进制表示"))
+        },
+        _ => Err(CalcError::Math(format!("{} 的参数必须是整数，实际为 {}", name, x))),
+    }
+}
+
+// 整数函数的结果：所有参数都精确时返回精确值，否则返回浮点数
+fn integer_result(value: BigInt, args: &[Number]) -> Number {
+    let exact = Number::Exact(Rational::from_integer(value));
+    if args.iter().all(Number::is_exact) {
+        exact
+    } else {
+        Number::Float(exact.to_f64())
+    }
+}
+
+// 取整类函数：精确值用有理数算法，浮点数用 f64 的方法
+fn round_with(x: &Number, float: fn(f64) -> f64, exact: fn(&Rational) -> BigInt) -> Number {
+    match x {
+        Number::Float(value) => Number::Float(float(*value)),
+        Number::Exact(value) => Number::Exact(Rational::from_integer(exact(value))),
+    }
+}
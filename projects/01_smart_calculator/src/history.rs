@@ -0,0 +1,60 @@
+// 计算历史 - 结构化的历史记录，支持撤销、重做、回放
+// 学习目标：枚举携带数据、Display trait、用 Vec 实现栈
+
+use std::fmt;
+
+use crate::error::CalcError;
+use crate::value::Value;
+
+// 一次计算的内容
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+    // 对当前值继续运算，例如 "+ 5"
+    Apply { operator: char, operand: Value },
+    // 计算完整的表达式，例如 "3 + 4 * 2"
+    Evaluate(String),
+}
+
+// 一条历史记录：做了什么、之前的值、得到的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub operation: Operation,
+    pub previous: Value,
+    pub result: Value,
+}
+
+impl fmt::Display for HistoryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.operation {
+            Operation::Apply { operator, operand } => {
+                write!(f, "{} {} {} = {}", self.previous, operator, operand, self.result)
+            },
+            Operation::Evaluate(source) => write!(f, "{} = {}", source, self.result),
+        }
+    }
+}
+
+// 解析 "2..5"、"2-5" 或 "3" 形式的历史范围（从 1 开始，包含两端）
+pub fn parse_range(text: &str, len: usize) -> Result<(usize, usize), CalcError> {
+    let parse_index = |part: &str| -> Result<usize, CalcError> {
+        part.trim()
+            .parse::<usize>()
+            .map_err(|_| CalcError::History(format!("无效的历史编号: {}", part.trim())))
+    };
+
+    let (start, end) = match text.split_once("..").or_else(|| text.split_once('-')) {
+        Some((start, end)) => (parse_index(start)?, parse_index(end)?),
+        None => {
+            let index = parse_index(text)?;
+            (index, index)
+        },
+    };
+
+    if start == 0 || end == 0 || start > end {
+        return Err(CalcError::History(format!("无效的历史范围: {}", text)));
+    }
+    if end > len {
+        return Err(CalcError::History(format!("历史记录只有 {} 条", len)));
+    }
+    Ok((start, end))
+}
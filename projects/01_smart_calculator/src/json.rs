@@ -0,0 +1,315 @@
+// JSON - --serve 模式使用的最小 JSON 解析和输出
+// 学习目标：递归下降解析、字符转义、Display 输出
+//
+// 只实现协议需要的部分：对象的键保持原来的顺序；数字保留原文，
+// 因此大整数原样输出，不会先变成浮点数丢失精度。
+
+use std::fmt;
+
+// 嵌套的最大深度，防止恶意输入导致栈溢出
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(String), // 数字的原文，例如 "42"、"-1.5e3"
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // 浮点数；NaN 和无穷大在 JSON 中没有对应的数字，输出为字符串
+    pub fn number(value: f64) -> Json {
+        if value.is_finite() {
+            Json::Number(value.to_string())
+        } else {
+            Json::String(value.to_string())
+        }
+    }
+
+    pub fn string(text: impl Into<String>) -> Json {
+        Json::String(text.into())
+    }
+
+    // 对象中的字段，不是对象或没有这个字段时返回 None
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(text) => write!(f, "{}", text),
+            Json::String(text) => write_string(f, text),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+// 输出带引号的字符串，转义引号、反斜杠和控制字符；中文等非 ASCII 字符原样输出
+fn write_string(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for ch in text.chars() {
+        match ch {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            ch if (ch as u32) < 0x20 => write!(f, "\\u{:04x}", ch as u32)?,
+            ch => write!(f, "{}", ch)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+// 解析一个完整的 JSON 值，前后可以有空白；出错时返回说明和位置
+pub fn parse(text: &str) -> Result<Json, String> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        position: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.position < parser.chars.len() {
+        return Err(parser.error("JSON 值后面有多余的内容"));
+    }
+    Ok(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!("第 {} 个字符: {}", self.position + 1, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|ch| matches!(ch, ' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("应该是 '{}'", expected)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        self.parse_nested(0)
+    }
+
+    fn parse_nested(&mut self, depth: usize) -> Result<Json, String> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("嵌套太深"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_object(depth),
+            Some('[') => self.parse_array(depth),
+            Some('"') => Ok(Json::String(self.parse_string()?)),
+            Some('-' | '0'..='9') => self.parse_number(),
+            Some(_) => {
+                let rest: String = self.chars[self.position..].iter().take(5).collect();
+                for (word, value) in [("null", Json::Null), ("true", Json::Bool(true)), ("false", Json::Bool(false))] {
+                    if rest.starts_with(word) {
+                        self.position += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error("无法识别的 JSON 值"))
+            },
+            None => Err(self.error("JSON 不完整")),
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("对象的键必须是字符串"));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_nested(depth + 1)?;
+            fields.push((key, value));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(Json::Object(fields));
+                },
+                _ => return Err(self.error("应该是 ',' 或 '}'")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Result<Json, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.parse_nested(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Json::Array(items));
+                },
+                _ => return Err(self.error("应该是 ',' 或 ']'")),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            let Some(ch) = self.peek() else {
+                return Err(self.error("字符串缺少结束的引号"));
+            };
+            self.position += 1;
+            match ch {
+                '"' => return Ok(text),
+                '\\' => text.push(self.parse_escape()?),
+                ch if (ch as u32) < 0x20 => return Err(self.error("字符串中不能直接包含控制字符")),
+                ch => text.push(ch),
+            }
+        }
+    }
+
+    // 反斜杠后面的转义序列，\u 可以是 UTF-16 代理对
+    fn parse_escape(&mut self) -> Result<char, String> {
+        let Some(ch) = self.peek() else {
+            return Err(self.error("转义序列不完整"));
+        };
+        self.position += 1;
+        let escaped = match ch {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let high = self.parse_hex4()?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    self.expect('\\')?;
+                    self.expect('u')?;
+                    let low = self.parse_hex4()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("无效的 UTF-16 代理对"));
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                char::from_u32(code).ok_or_else(|| self.error("无效的 Unicode 转义"))?
+            },
+            _ => return Err(self.error("未知的转义序列")),
+        };
+        Ok(escaped)
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        let digits: String = self.chars.iter().skip(self.position).take(4).collect();
+        if digits.len() != 4 {
+            return Err(self.error("\\u 后面需要 4 位十六进制数"));
+        }
+        let code = u32::from_str_radix(&digits, 16).map_err(|_| self.error("\\u 后面需要 4 位十六进制数"))?;
+        self.position += 4;
+        Ok(code)
+    }
+
+    // 数字：-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.position;
+        let digits = |parser: &mut Parser| {
+            let begin = parser.position;
+            while parser.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+                parser.position += 1;
+            }
+            parser.position > begin
+        };
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        if self.peek() == Some('0') {
+            self.position += 1;
+        } else if !digits(self) {
+            return Err(self.error("无效的数字"));
+        }
+        if self.peek() == Some('.') {
+            self.position += 1;
+            if !digits(self) {
+                return Err(self.error("小数点后面需要数字"));
+            }
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if !digits(self) {
+                return Err(self.error("指数部分需要数字"));
+            }
+        }
+        Ok(Json::Number(self.chars[start..self.position].iter().collect()))
+    }
+}
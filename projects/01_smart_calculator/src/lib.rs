@@ -0,0 +1,36 @@
+// 智能计算器库 - 表达式求值、单位、精确数值、历史和会话
+// 学习目标：库 crate 与二进制 crate 的划分、模块的可见性、pub use 重新导出
+//
+// 嵌入示例：
+//   let mut calculator = Calculator::new();
+//   let value = calculator.evaluate_input("3 + 4 * 2")?;
+//   assert_eq!(calculator.format_value(&value), "11");
+
+pub mod bigint;
+pub mod calculator;
+pub mod commands;
+pub mod complex;
+pub mod editor;
+pub mod error;
+pub mod format;
+pub mod functions;
+pub mod history;
+pub mod json;
+pub mod matrix;
+pub mod number;
+pub mod numeric;
+pub mod parser;
+pub mod rational;
+pub mod server;
+pub mod session;
+pub mod statistics;
+pub mod symbolic;
+pub mod units;
+pub mod value;
+
+pub use calculator::Calculator;
+pub use commands::{execute, Reply};
+pub use error::CalcError;
+pub use functions::Arity;
+pub use number::{Base, Number, NumberMode};
+pub use value::Value;
@@ -1,107 +1,158 @@
 // 智能计算器 - Rust 基础语法练习项目
 // 学习目标：变量、数据类型、控制流、错误处理
+// 计算逻辑在库 crate 中（src/lib.rs），这里只负责命令行参数、交互和输出
 
+use std::env;
+use std::fs;
 use std::io;
-use std::collections::HashMap;
+use std::path::Path;
+use std::process;
 
-// 计算器的主要状态
-struct Calculator {
-    current_value: f64,           // 当前值（可变）
-    memory: HashMap<String, f64>, // 变量存储（练习HashMap）
-    history: Vec<String>,         // 计算历史（练习Vec）
+use smart_calculator::commands;
+use smart_calculator::editor::{self, LineEditor, ReadResult};
+use smart_calculator::server;
+use smart_calculator::session;
+use smart_calculator::{execute, Calculator, Reply};
+
+// 脚本的来源
+enum Source {
+    Expression(String), // -e 表达式
+    File(String),       // 脚本文件，"-" 表示标准输入
 }
 
-impl Calculator {
-    // 创建新的计算器实例
-    fn new() -> Self {
-        Calculator {
-            current_value: 0.0,
-            memory: HashMap::new(),
-            history: Vec::new(),
-        }
-    }
-    
-    // 执行基本运算
-    fn calculate(&mut self, operator: char, operand: f64) -> Result<f64, String> {
-        let result = match operator {
-            '+' => self.current_value + operand,
-            '-' => self.current_value - operand,
-            '*' => self.current_value * operand,
-            '/' => {
-                if operand == 0.0 {
-                    return Err("错误：除零操作！".to_string());
-                }
-                self.current_value / operand
-            },
-            '^' => self.current_value.powf(operand),
-            _ => return Err(format!("不支持的操作符: {}", operator)),
-        };
-        
-        // 记录历史
-        let operation = format!("{} {} {} = {}", 
-            self.current_value, operator, operand, result);
-        self.history.push(operation);
-        
-        self.current_value = result;
-        Ok(result)
-    }
-    
-    // 设置当前值
-    fn set_value(&mut self, value: f64) {
-        self.current_value = value;
+// 脚本模式的命令行选项
+struct ScriptOptions {
+    sources: Vec<Source>, // 按命令行顺序执行
+    keep_going: bool,     // 出错后是否继续执行
+}
+
+const USAGE: &str = "用法:
+  calculator                     交互模式
+  calculator -e 表达式 [-e ...]  依次计算表达式并输出结果
+  calculator 脚本文件 [...]      执行脚本文件，每行一条输入，# 之后是注释；- 表示标准输入
+  calculator --serve             JSON 行协议：每行一个请求 {\"id\": 1, \"eval\": \"2+2\"}，每行一个响应
+选项:
+  -k, --keep-going               出错后继续执行后面的行（退出码仍为非零）
+  -h, --help                     显示本帮助";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        run_interactive();
+        return;
     }
-    
-    // 获取当前值
-    fn get_value(&self) -> f64 {
-        self.current_value
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
     }
-    
-    // 存储变量
-    fn store_variable(&mut self, name: String, value: f64) {
-        self.memory.insert(name, value);
+    if args.iter().any(|arg| arg == "--serve") {
+        if args.len() > 1 {
+            eprintln!("错误: --serve 不能和其他参数一起使用\n{}", USAGE);
+            process::exit(2);
+        }
+        if let Err(error) = server::run(io::stdin().lock(), io::stdout().lock()) {
+            eprintln!("错误: {}", error);
+            process::exit(1);
+        }
+        return;
     }
     
-    // 获取变量值
-    fn get_variable(&self, name: &str) -> Option<f64> {
-        self.memory.get(name).copied()
+    match parse_args(&args) {
+        Ok(options) => process::exit(run_script(&options)),
+        Err(error) => {
+            eprintln!("错误: {}\n{}", error, USAGE);
+            process::exit(2);
+        },
     }
+}
+
+// 解析脚本模式的命令行参数
+fn parse_args(args: &[String]) -> Result<ScriptOptions, String> {
+    let mut options = ScriptOptions {
+        sources: Vec::new(),
+        keep_going: false,
+    };
     
-    // 显示历史记录
-    fn show_history(&self) {
-        if self.history.is_empty() {
-            println!("没有计算历史");
-            return;
-        }
-        
-        println!("\n=== 计算历史 ===");
-        for (index, operation) in self.history.iter().enumerate() {
-            println!("{}: {}", index + 1, operation);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-e" | "--eval" => {
+                let expression = args.next().ok_or_else(|| format!("{} 后面缺少表达式", arg))?;
+                options.sources.push(Source::Expression(expression.clone()));
+            },
+            "-k" | "--keep-going" => options.keep_going = true,
+            "-" => options.sources.push(Source::File(arg.clone())),
+            _ if arg.starts_with('-') => return Err(format!("未知的选项: {}", arg)),
+            _ => options.sources.push(Source::File(arg.clone())),
         }
-        println!();
     }
     
-    // 清除历史
-    fn clear_history(&mut self) {
-        self.history.clear();
-        println!("历史记录已清除");
+    if options.sources.is_empty() {
+        return Err("没有指定要执行的表达式或脚本".to_string());
     }
+    Ok(options)
+}
+
+// 非交互地执行表达式和脚本，只输出计算结果，错误写到标准错误；返回进程退出码
+// 脚本模式从空白状态开始，不恢复也不自动保存会话，保证同一脚本的结果可重复
+fn run_script(options: &ScriptOptions) -> i32 {
+    let mut calculator = Calculator::new();
+    let mut failed = false;
     
-    // 显示所有变量
-    fn show_variables(&self) {
-        if self.memory.is_empty() {
-            println!("没有存储的变量");
-            return;
-        }
+    'sources: for source in &options.sources {
+        let text = match source {
+            Source::Expression(expression) => Ok(expression.clone()),
+            Source::File(path) if path == "-" => {
+                io::read_to_string(io::stdin()).map_err(|error| format!("无法读取标准输入: {}", error))
+            },
+            Source::File(path) => {
+                fs::read_to_string(path).map_err(|error| format!("无法读取 {}: {}", path, error))
+            },
+        };
+        let text = match text {
+            Ok(text) => text,
+            Err(error) => {
+                eprintln!("错误: {}", error);
+                failed = true;
+                if options.keep_going {
+                    continue;
+                }
+                break;
+            },
+        };
         
-        println!("\n=== 存储的变量 ===");
-        for (name, value) in &self.memory {
-            println!("{} = {}", name, value);
+        for (index, line) in text.lines().enumerate() {
+            match execute(&mut calculator, line) {
+                Ok(Reply::Result(value)) | Ok(Reply::Report(value, _)) => println!("{}", calculator.format_value(&value)),
+                Ok(Reply::Expression(expr)) => println!("{}", expr),
+                Ok(Reply::Listing(lines)) => {
+                    for line in lines {
+                        println!("{}", line);
+                    }
+                },
+                Ok(Reply::Message(_)) | Ok(Reply::Nothing) => {},
+                Ok(Reply::Quit) => break 'sources,
+                Err(error) => {
+                    // 指明出错的位置：文件名和行号，或者出错的 -e 表达式
+                    let location = match source {
+                        Source::Expression(expression) => format!("-e {}", expression),
+                        Source::File(path) => format!("{} 第 {} 行", path, index + 1),
+                    };
+                    eprintln!("错误 ({}): {}", location, error);
+                    failed = true;
+                    if !options.keep_going {
+                        break 'sources;
+                    }
+                },
+            }
         }
-        println!();
     }
+    
+    if failed { 1 } else { 0 }
 }
 
-fn main() {
+// 交互模式：读取一行、执行、显示结果
+fn run_interactive() {
     println!("=== Rust 智能计算器 ===");
     println!("这是一个练习 Rust 基础语法的项目");
     println!("支持基本运算、变量存储、历史记录等功能\n");
@@ -109,166 +160,85 @@ fn main() {
     // 创建计算器实例（练习结构体）
     let mut calculator = Calculator::new();
     
+    // 恢复上次退出时自动保存的会话；恢复失败时不自动保存，以免覆盖原文件
+    let mut autosave_path = session::default_path();
+    if let Some(path) = autosave_path.clone() {
+        if Path::new(&path).exists() {
+            match session::load(&path).and_then(|session| calculator.restore(session)) {
+                Ok(()) => println!("已恢复上次的会话: {}\n", path),
+                Err(error) => {
+                    println!("警告: 无法恢复会话，本次退出时不会自动保存\n  {}\n", error);
+                    autosave_path = None;
+                },
+            }
+        }
+    }
+    
     // 显示帮助信息
-    show_help();
+    for line in smart_calculator::commands::help_lines(&calculator) {
+        println!("{}", line);
+    }
+    
+    // 输入历史保存在单独的文件中，和会话无关
+    let mut editor = LineEditor::new(editor::default_history_path());
     
     // 主循环
     loop {
-        print!("\n当前值: {} > ", calculator.get_value());
-        
-        // 读取用户输入
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)
-            .expect("读取输入失败");
-        
-        let input = input.trim();
+        println!();
+        let prompt = format!("当前值: {} > ", calculator.format_value(calculator.get_value()));
         
-        // 处理特殊命令
-        match input {
-            "quit" | "q" | "exit" => {
-                println!("感谢使用智能计算器！");
+        // 读取用户输入（支持光标移动、输入历史和 Tab 补全）
+        let completer = |before: &str| commands::completions(&calculator, before);
+        let input = match editor.read_line(&prompt, &completer) {
+            Ok(ReadResult::Line(input)) => input,
+            Ok(ReadResult::Interrupted) => continue,
+            // 输入结束 (Ctrl-D) 时换行后退出，避免空转
+            Ok(ReadResult::Eof) => {
+                println!();
                 break;
             },
-            "help" | "h" => {
-                show_help();
-                continue;
-            },
-            "history" => {
-                calculator.show_history();
-                continue;
-            },
-            "clear_history" => {
-                calculator.clear_history();
-                continue;
-            },
-            "variables" | "vars" => {
-                calculator.show_variables();
-                continue;
+            Err(error) => {
+                println!("错误: 读取输入失败: {}", error);
+                break;
             },
-            "clear" | "c" => {
-                calculator.set_value(0.0);
-                println!("计算器已重置");
-                continue;
+        };
+        editor.add_history(&input);
+        
+        match execute(&mut calculator, &input) {
+            Ok(Reply::Result(value)) => println!("结果: {}", calculator.describe_value(&value)),
+            Ok(Reply::Report(value, report)) => {
+                println!("结果: {}", calculator.describe_value(&value));
+                println!("  {}", report);
             },
-            "" => continue,
-            _ => {
-                // 处理计算表达式
-                if let Err(error) = process_input(&mut calculator, input) {
-                    println!("错误: {}", error);
+            Ok(Reply::Expression(expr)) => println!("结果: {}", expr),
+            Ok(Reply::Message(message)) => println!("{}", message),
+            Ok(Reply::Listing(lines)) => {
+                for line in lines {
+                    println!("{}", line);
                 }
-            }
+            },
+            Ok(Reply::Nothing) => {},
+            Ok(Reply::Quit) => break,
+            Err(error) => println!("错误: {}", error),
         }
     }
-}
-
-// 处理用户输入的计算表达式
-fn process_input(calculator: &mut Calculator, input: &str) -> Result<(), String> {
-    // 检查是否是变量赋值 (例如: x = 42)
-    if input.contains('=') && !input.contains("==") {
-        return handle_variable_assignment(calculator, input);
-    }
     
-    // 检查是否是单独的数字或变量名
-    if let Ok(number) = input.parse::<f64>() {
-        calculator.set_value(number);
-        println!("当前值设置为: {}", number);
-        return Ok(());
+    if let Err(error) = editor.save() {
+        println!("警告: 保存输入历史失败: {}", error);
     }
     
-    // 检查是否是变量名
-    if let Some(value) = calculator.get_variable(input) {
-        calculator.set_value(value);
-        println!("从变量 {} 加载值: {}", input, value);
-        return Ok(());
-    }
-    
-    // 解析运算表达式 (例如: + 5, * 2.5, ^ 3)
-    parse_operation(calculator, input)
-}
-
-// 处理变量赋值
-fn handle_variable_assignment(calculator: &mut Calculator, input: &str) -> Result<(), String> {
-    let parts: Vec<&str> = input.split('=').collect();
-    if parts.len() != 2 {
-        return Err("变量赋值格式错误，应该是: 变量名 = 值".to_string());
-    }
-    
-    let var_name = parts[0].trim().to_string();
-    let value_str = parts[1].trim();
-    
-    // 尝试解析值
-    let value = if let Ok(num) = value_str.parse::<f64>() {
-        num
-    } else if let Some(var_value) = calculator.get_variable(value_str) {
-        var_value
-    } else {
-        return Err(format!("无法解析值: {}", value_str));
-    };
-    
-    calculator.store_variable(var_name.clone(), value);
-    println!("变量 {} 设置为: {}", var_name, value);
-    Ok(())
-}
-
-// 解析运算操作
-fn parse_operation(calculator: &mut Calculator, input: &str) -> Result<(), String> {
-    let input = input.trim();
-    
-    // 检查输入格式
-    if input.len() < 2 {
-        return Err("输入格式错误，应该是: 操作符 数值 (例如: + 5)".to_string());
-    }
-    
-    // 提取操作符
-    let operator = input.chars().next().unwrap();
-    let operand_str = &input[1..].trim();
-    
-    // 解析操作数
-    let operand = if let Ok(num) = operand_str.parse::<f64>() {
-        num
-    } else if let Some(var_value) = calculator.get_variable(operand_str) {
-        var_value
-    } else {
-        return Err(format!("无法解析操作数: {}", operand_str));
-    };
-    
-    // 执行计算
-    match calculator.calculate(operator, operand) {
-        Ok(result) => {
-            println!("结果: {}", result);
-            Ok(())
-        },
-        Err(error) => Err(error),
+    // 退出前自动保存会话
+    if let Some(path) = autosave_path {
+        if let Err(error) = session::save(&path, &calculator.snapshot()) {
+            println!("警告: 自动保存会话失败: {}", error);
+        }
     }
-}
-
-// 显示帮助信息
-fn show_help() {
-    println!("=== 帮助信息 ===");
-    println!("基本运算:");
-    println!("  + 数值    - 加法");
-    println!("  - 数值    - 减法");
-    println!("  * 数值    - 乘法");
-    println!("  / 数值    - 除法");
-    println!("  ^ 数值    - 幂运算");
-    println!();
-    println!("变量操作:");
-    println!("  数值            - 设置当前值");
-    println!("  变量名 = 数值   - 存储变量");
-    println!("  变量名          - 加载变量值");
-    println!();
-    println!("命令:");
-    println!("  help/h          - 显示帮助");
-    println!("  history         - 显示计算历史");
-    println!("  clear_history   - 清除历史");
-    println!("  variables/vars  - 显示所有变量");
-    println!("  clear/c         - 重置计算器");
-    println!("  quit/q/exit     - 退出程序");
-    println!();
+    println!("感谢使用智能计算器！");
 }
 
 // 演示函数：展示不同的数据类型使用
 #[allow(dead_code)]
+#[allow(clippy::approx_constant)]
 fn demonstrate_data_types() {
     println!("=== 数据类型演示 ===");
     
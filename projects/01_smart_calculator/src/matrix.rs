@@ -0,0 +1,453 @@
+// 矩阵和向量 - 矩阵乘法、转置、行列式、求逆、秩、解线性方程组
+// 学习目标：二维数据的一维存储、高斯消元、impl 块组织方法、运算符语义
+//
+// 矩阵就是元素都是列表的列表：[[1, 2], [3, 4]]，向量是纯数值的列表：[1, 2, 3]。
+// 精确模式下消元使用有理数，结果保持精确；浮点模式下选取绝对值最大的主元。
+
+use crate::bigint::BigInt;
+use crate::error::CalcError;
+use crate::number::{Number, NumberMode, MAX_EXACT_POW_BITS};
+use crate::parser::BinaryOp;
+use crate::rational::Rational;
+use crate::value::Value;
+
+// 参数是矩阵或向量的函数；solve 只有两个参数时才是解线性方程组 solve(A, b)
+pub const MATRIX_FUNCTIONS: [&str; 6] = ["transpose", "det", "inverse", "rank", "dot", "cross"];
+
+// 是否按矩阵函数计算
+pub fn is_matrix_call(name: &str, args: &[Value]) -> bool {
+    MATRIX_FUNCTIONS.contains(&name) || (name == "solve" && args.len() == 2)
+}
+
+// 计算矩阵函数，调用前用 is_matrix_call 判断
+pub fn call(name: &str, args: &[Value]) -> Result<Value, CalcError> {
+    let expected = match name {
+        "dot" | "cross" | "solve" => 2,
+        _ => 1,
+    };
+    if args.len() != expected {
+        return Err(CalcError::ArgumentCount {
+            name: name.to_string(),
+            expected: expected.to_string(),
+            actual: args.len(),
+        });
+    }
+
+    match name {
+        "transpose" => Ok(Matrix::from_value(name, &args[0])?.transpose().to_value()),
+        "det" => Matrix::from_value(name, &args[0])?.determinant().map(Value::Number),
+        "inverse" => Ok(Matrix::from_value(name, &args[0])?.inverse()?.to_value()),
+        "rank" => Ok(Value::Number(integer(Matrix::from_value(name, &args[0])?.rank()? as i64))),
+        "solve" => solve(&args[0], &args[1]),
+        "dot" => {
+            let (u, v) = (vector(name, &args[0])?, vector(name, &args[1])?);
+            if u.len() != v.len() {
+                return Err(shape_mismatch("求点积", &args[0], &args[1]));
+            }
+            inner_product(&u, &v).map(Value::Number)
+        },
+        "cross" => {
+            let (u, v) = (vector(name, &args[0])?, vector(name, &args[1])?);
+            let ([u1, u2, u3], [v1, v2, v3]) = (u.as_slice(), v.as_slice()) else {
+                return Err(CalcError::Math(format!(
+                    "cross 只能用于长度为 3 的向量: {} 和 {}",
+                    describe_shape(&args[0]),
+                    describe_shape(&args[1])
+                )));
+            };
+            // u × v = (u2 v3 - u3 v2, u3 v1 - u1 v3, u1 v2 - u2 v1)
+            let component = |a: &Number, b: &Number, c: &Number, d: &Number| {
+                let ad = Number::apply(BinaryOp::Mul, a, d)?;
+                let bc = Number::apply(BinaryOp::Mul, b, c)?;
+                Number::apply(BinaryOp::Sub, &ad, &bc).map(Value::Number)
+            };
+            Ok(Value::List(vec![
+                component(u2, u3, v2, v3)?,
+                component(u3, u1, v3, v1)?,
+                component(u1, u2, v1, v2)?,
+            ]))
+        },
+        _ => Err(CalcError::UndefinedFunction(name.to_string())),
+    }
+}
+
+// 是否是矩阵：非空的列表，每个元素都是列表
+pub fn is_matrix(value: &Value) -> bool {
+    matches!(value, Value::List(rows) if !rows.is_empty() && rows.iter().all(|row| matches!(row, Value::List(_))))
+}
+
+// 形状：向量是 [长度]，矩阵是 [行数, 列数]，纯数值是 []
+pub fn shape(value: &Value) -> Vec<usize> {
+    match value {
+        Value::List(items) => {
+            let mut shape = vec![items.len()];
+            if let Some(first) = items.first() {
+                shape.extend(shape_of_list(first));
+            }
+            shape
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn shape_of_list(value: &Value) -> Vec<usize> {
+    match value {
+        Value::List(_) => shape(value),
+        _ => Vec::new(),
+    }
+}
+
+// 错误信息中的形状，例如 "2×3 矩阵"、"长度 3 的向量"
+pub fn describe_shape(value: &Value) -> String {
+    let shape = shape(value);
+    match shape.as_slice() {
+        [] => "数值".to_string(),
+        [len] => format!("长度 {} 的向量", len),
+        [rows, cols] => format!("{}×{} 矩阵", rows, cols),
+        dims => {
+            let dims: Vec<String> = dims.iter().map(usize::to_string).collect();
+            format!("{} 列表", dims.join("×"))
+        },
+    }
+}
+
+pub fn shape_mismatch(operation: &'static str, lhs: &Value, rhs: &Value) -> CalcError {
+    CalcError::ShapeMismatch {
+        operation,
+        lhs: describe_shape(lhs),
+        rhs: describe_shape(rhs),
+    }
+}
+
+// 矩阵乘法：向量在左边时作为行向量，在右边时作为列向量，结果中的 1×n 或 n×1 仍然是向量
+pub fn product(lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+    let a = Matrix::from_operand(lhs, true)?;
+    let b = Matrix::from_operand(rhs, false)?;
+    if a.cols != b.rows {
+        return Err(shape_mismatch("相乘", lhs, rhs));
+    }
+    let result = a.multiply(&b)?;
+    match (is_matrix(lhs), is_matrix(rhs)) {
+        (false, _) | (_, false) => Ok(Value::List(result.data.into_iter().map(Value::Number).collect())),
+        _ => Ok(result.to_value()),
+    }
+}
+
+// 矩阵的整数次幂：A^0 是单位矩阵，负数次幂先求逆
+pub fn power(base: &Value, exponent: &Number) -> Result<Value, CalcError> {
+    let matrix = Matrix::from_value("^", base)?;
+    matrix.require_square("^")?;
+    let exponent = exponent
+        .convert(NumberMode::Exact)
+        .as_integer()
+        .and_then(|exponent| exponent.to_i64())
+        .ok_or_else(|| CalcError::Math(format!("矩阵只能做整数次幂: {}", exponent)))?;
+
+    let mut factor = if exponent < 0 { matrix.inverse()? } else { matrix };
+    let mut result = Matrix::identity(factor.rows);
+    // 快速幂：按指数的二进制位平方，每一步都检查结果的大小
+    let mut remaining = exponent.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result.power_step(&factor)?;
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            factor = factor.power_step(&factor)?;
+        }
+    }
+    Ok(result.to_value())
+}
+
+// 解线性方程组 A x = b，b 是向量时结果是向量，b 是矩阵时每一列分别求解
+fn solve(a: &Value, b: &Value) -> Result<Value, CalcError> {
+    let matrix = Matrix::from_value("solve", a)?;
+    matrix.require_square("solve")?;
+    let rhs = Matrix::from_operand(b, false)?;
+    if rhs.rows != matrix.rows {
+        return Err(shape_mismatch("组成方程组", a, b));
+    }
+
+    let mut augmented = matrix.augment(&rhs);
+    if augmented.reduce(matrix.cols)?.rank < matrix.rows {
+        return Err(CalcError::Math("系数矩阵是奇异的，方程组没有唯一解".to_string()));
+    }
+    let solution = augmented.columns(matrix.cols, augmented.cols);
+    if is_matrix(b) {
+        Ok(solution.to_value())
+    } else {
+        Ok(Value::List(solution.data.into_iter().map(Value::Number).collect()))
+    }
+}
+
+// 取出向量中的数值
+fn vector(name: &str, value: &Value) -> Result<Vec<Number>, CalcError> {
+    match value {
+        Value::List(items) if !is_matrix(value) => items.iter().map(|item| element(name, item)).collect(),
+        _ => Err(CalcError::InvalidInput(format!("{} 的参数必须是向量，例如 [1, 2, 3]: {}", name, value))),
+    }
+}
+
+fn element(name: &str, value: &Value) -> Result<Number, CalcError> {
+    value
+        .as_number()
+        .cloned()
+        .ok_or_else(|| CalcError::Unit(format!("{} 只接受纯数值的元素: {}", name, value)))
+}
+
+fn integer(value: i64) -> Number {
+    Number::Exact(Rational::from_integer(BigInt::from_i64(value)))
+}
+
+fn inner_product(u: &[Number], v: &[Number]) -> Result<Number, CalcError> {
+    u.iter().zip(v).try_fold(integer(0), |sum, (a, b)| {
+        Number::apply(BinaryOp::Add, &sum, &Number::apply(BinaryOp::Mul, a, b)?)
+    })
+}
+
+// 按行存储的矩阵
+#[derive(Debug, Clone)]
+struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<Number>,
+}
+
+// 消元的结果
+struct Reduction {
+    rank: usize,
+    determinant: Number, // 只有消元的列构成方阵时才有意义
+}
+
+impl Matrix {
+    fn identity(n: usize) -> Matrix {
+        let data = (0..n * n)
+            .map(|index| integer(i64::from(index / n == index % n)))
+            .collect();
+        Matrix { rows: n, cols: n, data }
+    }
+
+    // 从 [[1, 2], [3, 4]] 转换，每一行的长度必须相同
+    fn from_value(name: &str, value: &Value) -> Result<Matrix, CalcError> {
+        if !is_matrix(value) {
+            return Err(CalcError::InvalidInput(format!("{} 的参数必须是矩阵，例如 [[1, 2], [3, 4]]: {}", name, value)));
+        }
+        let Value::List(rows) = value else {
+            unreachable!("is_matrix 已经检查");
+        };
+        let mut data = Vec::new();
+        let mut cols = None;
+        for row in rows {
+            let row = vector(name, row)?;
+            match cols {
+                Some(cols) if cols != row.len() => {
+                    return Err(CalcError::InvalidInput(format!("矩阵的每一行长度必须相同: {}", value)));
+                },
+                _ => cols = Some(row.len()),
+            }
+            data.extend(row);
+        }
+        Ok(Matrix {
+            rows: rows.len(),
+            cols: cols.unwrap_or(0),
+            data,
+        })
+    }
+
+    // 乘法的操作数：向量在左边作为 1×n 的行向量，在右边作为 n×1 的列向量
+    fn from_operand(value: &Value, left: bool) -> Result<Matrix, CalcError> {
+        if is_matrix(value) {
+            return Matrix::from_value("*", value);
+        }
+        let data = vector("*", value)?;
+        let (rows, cols) = if left { (1, data.len()) } else { (data.len(), 1) };
+        Ok(Matrix { rows, cols, data })
+    }
+
+    fn to_value(&self) -> Value {
+        let rows = (0..self.rows)
+            .map(|row| Value::List(self.row(row).iter().cloned().map(Value::Number).collect()))
+            .collect();
+        Value::List(rows)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &Number {
+        &self.data[row * self.cols + col]
+    }
+
+    fn row(&self, row: usize) -> &[Number] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    fn require_square(&self, name: &str) -> Result<(), CalcError> {
+        if self.rows != self.cols {
+            return Err(CalcError::Math(format!("{} 需要方阵，实际是 {}×{} 矩阵", name, self.rows, self.cols)));
+        }
+        Ok(())
+    }
+
+    fn transpose(&self) -> Matrix {
+        let data = (0..self.cols)
+            .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+            .map(|(row, col)| self.get(row, col).clone())
+            .collect();
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    fn multiply(&self, other: &Matrix) -> Result<Matrix, CalcError> {
+        let columns = other.transpose();
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for row in 0..self.rows {
+            for col in 0..other.cols {
+                data.push(inner_product(self.row(row), columns.row(col))?);
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: other.cols,
+            data,
+        })
+    }
+
+    // 矩阵幂中的一次乘法：和数值的幂一样限制精确结果的位数，浮点结果溢出时报错而不是返回 inf 或 NaN
+    fn power_step(&self, other: &Matrix) -> Result<Matrix, CalcError> {
+        // 乘积元素的位数大约是两个因子元素位数之和，先估计，避免在巨大的整数上做乘法
+        if self.exact_bits() + other.exact_bits() > MAX_EXACT_POW_BITS {
+            return Err(CalcError::Overflow("精确结果过大，请改用 mode float".to_string()));
+        }
+        let product = self.multiply(other)?;
+        if product.exact_bits() > MAX_EXACT_POW_BITS {
+            return Err(CalcError::Overflow("精确结果过大，请改用 mode float".to_string()));
+        }
+        if product.data.iter().any(|x| matches!(x, Number::Float(value) if !value.is_finite())) {
+            return Err(CalcError::Overflow("矩阵幂的结果超出浮点数范围".to_string()));
+        }
+        Ok(product)
+    }
+
+    // 精确元素中分子或分母的最大位数，浮点元素不计
+    fn exact_bits(&self) -> u64 {
+        self.data
+            .iter()
+            .map(|x| match x {
+                Number::Exact(value) => value.numer().bits().max(value.denom().bits()),
+                Number::Float(_) => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    // 在右边拼接另一个行数相同的矩阵：[A | B]
+    fn augment(&self, other: &Matrix) -> Matrix {
+        let mut data = Vec::with_capacity(self.rows * (self.cols + other.cols));
+        for row in 0..self.rows {
+            data.extend_from_slice(self.row(row));
+            data.extend_from_slice(other.row(row));
+        }
+        Matrix {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            data,
+        }
+    }
+
+    // 取出第 start 到 end - 1 列
+    fn columns(&self, start: usize, end: usize) -> Matrix {
+        let data = (0..self.rows)
+            .flat_map(|row| self.row(row)[start..end].to_vec())
+            .collect();
+        Matrix {
+            rows: self.rows,
+            cols: end - start,
+            data,
+        }
+    }
+
+    fn determinant(&self) -> Result<Number, CalcError> {
+        self.require_square("det")?;
+        let mut reduced = self.clone();
+        let reduction = reduced.reduce(self.cols)?;
+        if reduction.rank < self.rows {
+            return Ok(integer(0));
+        }
+        Ok(reduction.determinant)
+    }
+
+    fn inverse(&self) -> Result<Matrix, CalcError> {
+        self.require_square("inverse")?;
+        let mut augmented = self.augment(&Matrix::identity(self.rows));
+        if augmented.reduce(self.cols)?.rank < self.rows {
+            return Err(CalcError::Math("矩阵是奇异的（行列式为零），不能求逆".to_string()));
+        }
+        Ok(augmented.columns(self.cols, augmented.cols))
+    }
+
+    fn rank(&self) -> Result<usize, CalcError> {
+        let mut reduced = self.clone();
+        Ok(reduced.reduce(self.cols)?.rank)
+    }
+
+    // 高斯-约当消元：把前 columns 列化成简化行阶梯形，同一行的其余列跟着变换
+    fn reduce(&mut self, columns: usize) -> Result<Reduction, CalcError> {
+        // 浮点数的主元小于这个值时视为零，避免舍入误差把奇异矩阵当成满秩；
+        // 只看消元的列，拼接在右边的单位矩阵或常数列不影响阈值
+        let largest = (0..self.rows)
+            .flat_map(|row| &self.row(row)[..columns])
+            .map(|x| x.to_f64().abs())
+            .fold(0.0, f64::max);
+        let threshold = f64::EPSILON * self.rows.max(columns) as f64 * largest;
+        let negligible = |x: &Number| match x {
+            Number::Exact(value) => value.is_zero(),
+            Number::Float(value) => value.abs() <= threshold,
+        };
+
+        let mut rank = 0;
+        let mut determinant = integer(1);
+        for col in 0..columns {
+            if rank == self.rows {
+                break;
+            }
+            // 选取这一列中绝对值最大的元素作为主元
+            let pivot = (rank..self.rows)
+                .filter(|&row| !negligible(self.get(row, col)))
+                .max_by(|&a, &b| self.get(a, col).abs().compare(&self.get(b, col).abs()));
+            let Some(pivot) = pivot else {
+                continue;
+            };
+            if pivot != rank {
+                self.swap_rows(pivot, rank);
+                determinant = determinant.neg();
+            }
+
+            let pivot_value = self.get(rank, col).clone();
+            determinant = Number::apply(BinaryOp::Mul, &determinant, &pivot_value)?;
+            for index in 0..self.cols {
+                let scaled = Number::apply(BinaryOp::Div, self.get(rank, index), &pivot_value)?;
+                self.data[rank * self.cols + index] = scaled;
+            }
+            for row in (0..self.rows).filter(|&row| row != rank) {
+                let factor = self.get(row, col).clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for index in 0..self.cols {
+                    let delta = Number::apply(BinaryOp::Mul, &factor, self.get(rank, index))?;
+                    let value = Number::apply(BinaryOp::Sub, self.get(row, index), &delta)?;
+                    self.data[row * self.cols + index] = value;
+                }
+            }
+            rank += 1;
+        }
+        Ok(Reduction { rank, determinant })
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.data.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
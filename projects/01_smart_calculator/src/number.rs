@@ -0,0 +1,339 @@
+// 数值后端 - 浮点数和精确有理数两种表示
+// 学习目标：枚举封装不同实现、模式匹配组合两个值、类型转换
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::bigint::BigInt;
+use crate::error::CalcError;
+use crate::parser::{self, BinaryOp};
+use crate::rational::Rational;
+
+// 精确幂运算允许的最大结果位数，防止 10^10^10 之类的输入耗尽内存
+pub const MAX_EXACT_POW_BITS: u64 = 1 << 20;
+
+// programmer 模式可以选择的字长（位数）
+pub const WORD_SIZES: [u32; 4] = [8, 16, 32, 64];
+
+// 计算模式：决定数字字面量被解析成哪种数值
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberMode {
+    Float,      // 双精度浮点数（默认）
+    Exact,      // 任意精度有理数
+    Programmer, // 固定字长的有符号整数，溢出时回绕
+}
+
+impl NumberMode {
+    pub fn name(self) -> &'static str {
+        match self {
+            NumberMode::Float => "float",
+            NumberMode::Exact => "exact",
+            NumberMode::Programmer => "programmer",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<NumberMode> {
+        match name {
+            "float" => Some(NumberMode::Float),
+            "exact" => Some(NumberMode::Exact),
+            "programmer" => Some(NumberMode::Programmer),
+            _ => None,
+        }
+    }
+}
+
+// programmer 模式下整数的显示进制
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Base {
+    Dec,
+    Hex,
+    Bin,
+    Oct,
+}
+
+impl Base {
+    pub fn name(self) -> &'static str {
+        match self {
+            Base::Dec => "dec",
+            Base::Hex => "hex",
+            Base::Bin => "bin",
+            Base::Oct => "oct",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Base> {
+        match name {
+            "dec" => Some(Base::Dec),
+            "hex" => Some(Base::Hex),
+            "bin" => Some(Base::Bin),
+            "oct" => Some(Base::Oct),
+            _ => None,
+        }
+    }
+
+    // 按进制显示 bits 位整数；非十进制显示补码，例如 8 位的 -1 显示为 0xFF
+    pub fn format(self, value: i64, bits: u32) -> String {
+        let mask = if bits >= 64 { u64::MAX } else { (1u64 << bits) - 1 };
+        let pattern = value as u64 & mask;
+        match self {
+            Base::Dec => value.to_string(),
+            Base::Hex => format!("0x{:X}", pattern),
+            Base::Bin => format!("0b{:b}", pattern),
+            Base::Oct => format!("0o{:o}", pattern),
+        }
+    }
+}
+
+// 计算器中的数值
+// 两个精确值运算结果仍然精确；只要有一方是浮点数，结果就是浮点数
+#[derive(Debug, Clone, PartialEq)]
+pub enum Number {
+    Float(f64),
+    Exact(Rational),
+}
+
+impl Number {
+    pub fn zero() -> Number {
+        Number::Float(0.0)
+    }
+
+    // 按计算模式解析数字字面量，0x/0b/0o 前缀的整数在所有模式下都可以使用
+    pub fn parse_literal(text: &str, mode: NumberMode) -> Option<Number> {
+        if let Some(value) = parser::parse_radix_literal(text) {
+            return Some(match mode {
+                NumberMode::Float => Number::Float(value as f64),
+                NumberMode::Exact | NumberMode::Programmer => {
+                    Number::Exact(Rational::from_integer(BigInt::from_u64(value)))
+                },
+            });
+        }
+        match mode {
+            NumberMode::Float => text.parse::<f64>().ok().map(Number::Float),
+            NumberMode::Exact | NumberMode::Programmer => Rational::parse_decimal(text).map(Number::Exact),
+        }
+    }
+
+    // 转换到指定模式：浮点数按最短十进制表示转成精确值（0.1 -> 1/10）
+    pub fn convert(&self, mode: NumberMode) -> Number {
+        match (self, mode) {
+            (Number::Exact(value), NumberMode::Float) => Number::Float(value.to_f64()),
+            (Number::Float(value), NumberMode::Exact | NumberMode::Programmer) => match Rational::from_f64(*value) {
+                Some(exact) => Number::Exact(exact),
+                None => self.clone(),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Number::Float(value) => *value,
+            Number::Exact(value) => value.to_f64(),
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Number::Exact(_))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Number::Float(value) => *value == 0.0,
+            Number::Exact(value) => value.is_zero(),
+        }
+    }
+
+    // 精确整数时返回 BigInt
+    pub fn as_integer(&self) -> Option<BigInt> {
+        match self {
+            Number::Exact(value) if value.is_integer() => Some(value.numer().clone()),
+            _ => None,
+        }
+    }
+
+    pub fn neg(&self) -> Number {
+        match self {
+            Number::Float(value) => Number::Float(-value),
+            Number::Exact(value) => Number::Exact(value.neg()),
+        }
+    }
+
+    pub fn abs(&self) -> Number {
+        match self {
+            Number::Float(value) => Number::Float(value.abs()),
+            Number::Exact(value) => Number::Exact(value.abs()),
+        }
+    }
+
+    // 按位取反：~x = -x - 1（补码表示下的恒等式）
+    pub fn bit_not(&self) -> Result<Number, CalcError> {
+        let value = integer_operand(self)?;
+        Ok(integer_like(&(&(-value) - &BigInt::one()), self, self))
+    }
+
+    // 转换为 bits 位有符号整数：先截掉小数部分，超出范围时按补码回绕
+    pub fn wrap_to_word(&self, bits: u32) -> Result<Number, CalcError> {
+        let integer = match self {
+            Number::Exact(value) if value.is_negative() => value.ceil(),
+            Number::Exact(value) => value.floor(),
+            Number::Float(value) if value.is_finite() => {
+                BigInt::parse(&format!("{:.0}", value.trunc())).expect("整数的十进制表示")
+            },
+            Number::Float(value) => return Err(CalcError::Math(format!("{} 不能转换为整数", value))),
+        };
+
+        let modulus = BigInt::from_u64(2).pow(bits);
+        let half = BigInt::from_u64(2).pow(bits - 1);
+        let (_, mut remainder) = integer.div_rem(&modulus).expect("除数不为零");
+        if remainder.is_negative() {
+            remainder = &remainder + &modulus;
+        }
+        if remainder >= half {
+            remainder = &remainder - &modulus;
+        }
+        Ok(Number::Exact(Rational::from_integer(remainder)))
+    }
+
+    // 精确整数且能放进 i64 时返回 Some，programmer 模式下按进制显示时使用
+    pub fn to_i64(&self) -> Option<i64> {
+        self.as_integer()?.to_i64()
+    }
+
+    // 比较大小，浮点数中的 NaN 视为相等
+    pub fn compare(&self, other: &Number) -> Ordering {
+        match (self, other) {
+            (Number::Exact(a), Number::Exact(b)) => a.cmp(b),
+            _ => self.to_f64().partial_cmp(&other.to_f64()).unwrap_or(Ordering::Equal),
+        }
+    }
+
+    // 执行二元运算
+    pub fn apply(op: BinaryOp, lhs: &Number, rhs: &Number) -> Result<Number, CalcError> {
+        if op.is_bitwise() {
+            return Number::apply_bitwise(op, lhs, rhs);
+        }
+        if let (Number::Exact(a), Number::Exact(b)) = (lhs, rhs) {
+            if let Some(result) = Number::apply_exact(op, a, b)? {
+                return Ok(Number::Exact(result));
+            }
+        }
+
+        let (lhs, rhs) = (lhs.to_f64(), rhs.to_f64());
+        let result = match op {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => {
+                if rhs == 0.0 {
+                    return Err(CalcError::DivisionByZero);
+                }
+                lhs / rhs
+            },
+            BinaryOp::Pow => {
+                // 负数的非整数次幂是复数：(-8)^(1/3) = 1 + 1.732…i
+                if lhs < 0.0 && rhs.is_finite() && rhs.fract() != 0.0 {
+                    return Err(CalcError::NotReal(format!(
+                        "负数的非整数次幂是复数: ({})^{}，可以用 complex on 开启复数运算",
+                        lhs, rhs
+                    )));
+                }
+                lhs.powf(rhs)
+            },
+            _ => unreachable!("位运算已经处理"),
+        };
+        Ok(Number::Float(result))
+    }
+
+    // 位运算：操作数必须是整数；与、或、异或按 64 位补码计算，移位按乘除 2 的幂计算
+    fn apply_bitwise(op: BinaryOp, lhs: &Number, rhs: &Number) -> Result<Number, CalcError> {
+        let (a, b) = (integer_operand(lhs)?, integer_operand(rhs)?);
+        let result = match op {
+            BinaryOp::Shl | BinaryOp::Shr => {
+                let shift = b
+                    .to_i64()
+                    .filter(|shift| (0..=MAX_EXACT_POW_BITS as i64).contains(shift))
+                    .ok_or_else(|| CalcError::Math(format!("移位位数必须是 0 到 {} 之间的整数", MAX_EXACT_POW_BITS)))?;
+                let power = BigInt::from_u64(2).pow(shift as u32);
+                if op == BinaryOp::Shl {
+                    &a * &power
+                } else {
+                    // 算术右移：向下取整，-1 >> 1 = -1
+                    Rational::new(a, power).expect("除数不为零").floor()
+                }
+            },
+            _ => {
+                let (Some(a), Some(b)) = (a.to_i64(), b.to_i64()) else {
+                    return Err(CalcError::Overflow("位运算的操作数超出 64 位范围".to_string()));
+                };
+                let result = match op {
+                    BinaryOp::BitAnd => a & b,
+                    BinaryOp::BitOr => a | b,
+                    _ => a ^ b,
+                };
+                BigInt::from_i64(result)
+            },
+        };
+        Ok(integer_like(&result, lhs, rhs))
+    }
+
+    // 精确运算；返回 Ok(None) 表示结果无法精确表示，需要退回浮点数
+    fn apply_exact(op: BinaryOp, lhs: &Rational, rhs: &Rational) -> Result<Option<Rational>, CalcError> {
+        let result = match op {
+            BinaryOp::Add => lhs.add(rhs),
+            BinaryOp::Sub => lhs.sub(rhs),
+            BinaryOp::Mul => lhs.mul(rhs),
+            BinaryOp::Div => lhs.div(rhs).ok_or(CalcError::DivisionByZero)?,
+            BinaryOp::Pow => {
+                // 只有整数指数能保持精确，例如 (2/3)^5；2^0.5 是无理数
+                if !rhs.is_integer() {
+                    return Ok(None);
+                }
+                let base_bits = lhs.numer().bits().max(lhs.denom().bits());
+                let exponent = match rhs.numer().to_i64() {
+                    Some(exponent) if exponent.unsigned_abs() <= u32::MAX as u64 => exponent,
+                    // 0、1、-1 的幂只取决于指数的符号和奇偶
+                    _ if base_bits <= 1 => {
+                        let parity = if rhs.numer().is_even() { 2 } else { 1 };
+                        if rhs.is_negative() { -parity } else { parity }
+                    },
+                    _ => return Err(CalcError::Overflow("精确结果过大，请改用 mode float".to_string())),
+                };
+                if base_bits > 1 && exponent.unsigned_abs() * base_bits > MAX_EXACT_POW_BITS {
+                    return Err(CalcError::Overflow("精确结果过大，请改用 mode float".to_string()));
+                }
+                lhs.pow(exponent).ok_or(CalcError::DivisionByZero)?
+            },
+            _ => unreachable!("位运算已经处理"),
+        };
+        Ok(Some(result))
+    }
+}
+
+// 位运算的操作数转换为整数
+fn integer_operand(x: &Number) -> Result<BigInt, CalcError> {
+    match x {
+        Number::Exact(value) if value.is_integer() => Ok(value.numer().clone()),
+        Number::Float(value) if value.fract() == 0.0 => {
+            Ok(BigInt::parse(&format!("{:.0}", value)).expect("整数的十进制表示"))
+        },
+        _ => Err(CalcError::Math(format!("位运算的操作数必须是整数，实际为 {}", x))),
+    }
+}
+
+// 整数结果：两个操作数都精确时返回精确值，否则返回浮点数
+fn integer_like(value: &BigInt, lhs: &Number, rhs: &Number) -> Number {
+    if lhs.is_exact() && rhs.is_exact() {
+        Number::Exact(Rational::from_integer(value.clone()))
+    } else {
+        Number::Float(value.to_f64())
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Number::Float(value) => write!(f, "{}", value),
+            Number::Exact(value) => write!(f, "{}", value),
+        }
+    }
+}
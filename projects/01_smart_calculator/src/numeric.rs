@@ -0,0 +1,287 @@
+// 数值方法 - 方程求根和定积分
+// 学习目标：闭包作为参数（FnMut）、迭代算法、递归、结构体返回多个结果
+//
+// 这里的函数只处理 f64 -> f64 的函数，由调用者负责把表达式包装成闭包。
+
+use std::fmt;
+
+use crate::error::CalcError;
+use crate::parser::Expr;
+
+// 参数是表达式和变量名、按需计算的函数：solve(方程, x, 初始值)、integrate(f, x, a, b)、sum(f, i, 1, n)
+pub const NUMERIC_FUNCTIONS: [&str; 3] = ["solve", "integrate", "sum"];
+
+// 是否是数值方法调用；只有一个参数的 sum 是对列表求和，由统计函数处理，
+// 两个参数的 solve 是解线性方程组 solve(A, b)，由矩阵函数处理
+pub fn is_numeric_call(name: &str, args: &[Expr]) -> bool {
+    match name {
+        "sum" => args.len() != 1,
+        "solve" => args.len() != 2,
+        _ => NUMERIC_FUNCTIONS.contains(&name),
+    }
+}
+
+// 迭代次数的上限，防止设置过大导致长时间没有响应
+pub const MAX_ITERATIONS_LIMIT: u32 = 1_000_000;
+
+// 自适应 Simpson 积分的最大递归深度，区间最多被二分这么多次
+const MAX_SIMPSON_DEPTH: u32 = 50;
+
+// 求根失败后寻找变号区间时，区间每次扩大的倍数
+const BRACKET_GROWTH: f64 = 1.6;
+
+// 数值方法的精度设置，可以用 tolerance 和 iterations 命令修改
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericSettings {
+    pub tolerance: f64,       // 收敛判据：步长或区间宽度小于 tolerance * max(1, |x|)
+    pub max_iterations: u32,  // 求根最多迭代的次数
+}
+
+impl Default for NumericSettings {
+    fn default() -> Self {
+        NumericSettings {
+            tolerance: 1e-10,
+            max_iterations: 100,
+        }
+    }
+}
+
+impl NumericSettings {
+    // 解析容差设置，必须是正的有限数
+    pub fn parse_tolerance(text: &str) -> Option<f64> {
+        text.trim().parse::<f64>().ok().filter(|value| value.is_finite() && *value > 0.0)
+    }
+
+    // 解析迭代次数设置，范围是 1 到 MAX_ITERATIONS_LIMIT
+    pub fn parse_iterations(text: &str) -> Option<u32> {
+        text.trim().parse::<u32>().ok().filter(|value| (1..=MAX_ITERATIONS_LIMIT).contains(value))
+    }
+}
+
+// 求根方法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    Newton,
+    Bisection,
+}
+
+impl Method {
+    pub fn name(self) -> &'static str {
+        match self {
+            Method::Newton => "牛顿法",
+            Method::Bisection => "二分法",
+        }
+    }
+}
+
+// 求根结果和收敛情况
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    pub root: f64,
+    pub residual: f64, // |f(root)|
+    pub iterations: u32,
+    pub method: Method,
+}
+
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}迭代 {} 次收敛，|f(x)| = {:e}",
+            self.method.name(),
+            self.iterations,
+            self.residual
+        )
+    }
+}
+
+// 积分结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Integral {
+    pub value: f64,
+    pub error: f64, // 误差估计
+    pub evaluations: u32,
+}
+
+impl fmt::Display for Integral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "自适应 Simpson 法，计算 {} 次函数值，误差估计 {:e}",
+            self.evaluations, self.error
+        )
+    }
+}
+
+// 被求解、被积分的函数；计算出错（例如超出定义域）时返回 Err
+pub type RealFn<'a> = dyn FnMut(f64) -> Result<f64, CalcError> + 'a;
+
+// 求根：先从 guess 开始用牛顿法，不收敛时在 guess 附近寻找变号区间再用二分法
+// derivative 为 None 时用中心差分近似导数
+pub fn solve(
+    f: &mut RealFn,
+    derivative: Option<&mut RealFn>,
+    guess: f64,
+    settings: NumericSettings,
+) -> Result<Solution, CalcError> {
+    let newton_error = match newton(f, derivative, guess, settings) {
+        Ok(solution) => return Ok(solution),
+        Err(error) => error,
+    };
+    let (a, b) = find_bracket(f, guess, settings).map_err(|_| newton_error)?;
+    bisection(f, a, b, settings)
+}
+
+// 牛顿法：x = x - f(x) / f'(x)
+pub fn newton(
+    f: &mut RealFn,
+    mut derivative: Option<&mut RealFn>,
+    guess: f64,
+    settings: NumericSettings,
+) -> Result<Solution, CalcError> {
+    let mut x = guess;
+    for iteration in 1..=settings.max_iterations {
+        let fx = f(x)?;
+        if fx == 0.0 {
+            return Ok(Solution { root: x, residual: 0.0, iterations: iteration, method: Method::Newton });
+        }
+        let slope = match derivative.as_mut() {
+            Some(derivative) => derivative(x)?,
+            None => {
+                let h = 1e-6 * x.abs().max(1.0);
+                (f(x + h)? - f(x - h)?) / (2.0 * h)
+            },
+        };
+        if slope == 0.0 || !slope.is_finite() {
+            return Err(CalcError::Math(format!("牛顿法在 x = {} 处导数为零或无定义", x)));
+        }
+
+        let step = fx / slope;
+        x -= step;
+        if !x.is_finite() {
+            return Err(CalcError::Math("牛顿法发散".to_string()));
+        }
+        if step.abs() <= settings.tolerance * x.abs().max(1.0) {
+            let residual = f(x)?.abs();
+            return Ok(Solution { root: x, residual, iterations: iteration, method: Method::Newton });
+        }
+    }
+    Err(CalcError::Math(format!(
+        "牛顿法迭代 {} 次没有收敛（最后的 x = {}），可以换一个初始值或用 iterations 命令增加迭代次数",
+        settings.max_iterations, x
+    )))
+}
+
+// 二分法：[a, b] 两端的函数值必须异号
+pub fn bisection(f: &mut RealFn, mut a: f64, mut b: f64, settings: NumericSettings) -> Result<Solution, CalcError> {
+    let (mut fa, fb) = (f(a)?, f(b)?);
+    if fa == 0.0 || fb == 0.0 {
+        let root = if fa == 0.0 { a } else { b };
+        return Ok(Solution { root, residual: 0.0, iterations: 0, method: Method::Bisection });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(CalcError::Math(format!("f({}) 和 f({}) 同号，区间内不一定有根", a, b)));
+    }
+
+    for iteration in 1..=settings.max_iterations {
+        let mid = a + (b - a) / 2.0;
+        let fm = f(mid)?;
+        if fm == 0.0 || (b - a).abs() / 2.0 <= settings.tolerance * mid.abs().max(1.0) {
+            return Ok(Solution { root: mid, residual: fm.abs(), iterations: iteration, method: Method::Bisection });
+        }
+        if fm.signum() == fa.signum() {
+            a = mid;
+            fa = fm;
+        } else {
+            b = mid;
+        }
+    }
+    Err(CalcError::Math(format!(
+        "二分法迭代 {} 次没有收敛（区间 [{}, {}]），可以用 iterations 命令增加迭代次数",
+        settings.max_iterations, a, b
+    )))
+}
+
+// 从 guess 向两边逐步扩大，直到找到函数值变号的区间
+fn find_bracket(f: &mut RealFn, guess: f64, settings: NumericSettings) -> Result<(f64, f64), CalcError> {
+    let f_guess = f(guess)?;
+    let mut distance = 0.01 * guess.abs().max(1.0);
+    for _ in 0..settings.max_iterations {
+        for other in [guess - distance, guess + distance] {
+            // 超出定义域的点跳过
+            if let Ok(value) = f(other) {
+                if value.is_finite() && value.signum() != f_guess.signum() {
+                    return Ok((guess.min(other), guess.max(other)));
+                }
+            }
+        }
+        distance *= BRACKET_GROWTH;
+    }
+    Err(CalcError::Math(format!("在 {} 附近找不到变号区间", guess)))
+}
+
+// 自适应 Simpson 积分：误差估计超过容差的子区间继续二分
+pub fn integrate(f: &mut RealFn, a: f64, b: f64, settings: NumericSettings) -> Result<Integral, CalcError> {
+    let mut integrator = Simpson { f, evaluations: 0 };
+    let (fa, fm, fb) = (integrator.eval(a)?, integrator.eval((a + b) / 2.0)?, integrator.eval(b)?);
+    let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+    let (value, error) = integrator.refine(a, b, fa, fm, fb, whole, settings.tolerance, MAX_SIMPSON_DEPTH)?;
+    Ok(Integral {
+        value,
+        error,
+        evaluations: integrator.evaluations,
+    })
+}
+
+struct Simpson<'a, 'b> {
+    f: &'a mut RealFn<'b>,
+    evaluations: u32,
+}
+
+impl Simpson<'_, '_> {
+    fn eval(&mut self, x: f64) -> Result<f64, CalcError> {
+        self.evaluations += 1;
+        let value = (self.f)(x)?;
+        if !value.is_finite() {
+            return Err(CalcError::Math(format!("被积函数在 x = {} 处无定义", x)));
+        }
+        Ok(value)
+    }
+
+    // 返回 (积分值, 误差估计)；whole 是整个区间上的 Simpson 近似
+    #[allow(clippy::too_many_arguments)]
+    fn refine(
+        &mut self,
+        a: f64,
+        b: f64,
+        fa: f64,
+        fm: f64,
+        fb: f64,
+        whole: f64,
+        tolerance: f64,
+        depth: u32,
+    ) -> Result<(f64, f64), CalcError> {
+        let mid = (a + b) / 2.0;
+        let (left_mid, right_mid) = ((a + mid) / 2.0, (mid + b) / 2.0);
+        let (f_left, f_right) = (self.eval(left_mid)?, self.eval(right_mid)?);
+        let left = (mid - a) / 6.0 * (fa + 4.0 * f_left + fm);
+        let right = (b - mid) / 6.0 * (fm + 4.0 * f_right + fb);
+        let delta = left + right - whole;
+
+        // 容差不小于舍入误差，否则在函数值很大的区间上永远达不到
+        let tolerance = tolerance.max(f64::EPSILON * (left + right).abs());
+        if delta.abs() <= 15.0 * tolerance {
+            // Richardson 外推：误差大约是 delta / 15
+            return Ok((left + right + delta / 15.0, (delta / 15.0).abs()));
+        }
+        if depth == 0 {
+            return Err(CalcError::Math(format!(
+                "积分在 x = {} 附近没有收敛，被积函数可能在区间内发散",
+                mid
+            )));
+        }
+        let (left_value, left_error) = self.refine(a, mid, fa, f_left, fm, left, tolerance / 2.0, depth - 1)?;
+        let (right_value, right_error) = self.refine(mid, b, fm, f_right, fb, right, tolerance / 2.0, depth - 1)?;
+        Ok((left_value + right_value, left_error + right_error))
+    }
+}
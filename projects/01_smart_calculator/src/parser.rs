@@ -0,0 +1,616 @@
+// 表达式解析器 - 词法分析 + 优先级爬升（precedence climbing）
+// 学习目标：枚举、模式匹配、递归、Box 智能指针、错误处理
+
+use std::fmt;
+
+// 词法单元（练习带数据的枚举）
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(String),
+    Ident(String),
+    Operator(char),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Equals,
+}
+
+// 带列号的词法单元，列号从 1 开始，按字符计数
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    column: usize,
+}
+
+// 二元运算符
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    BitAnd, // &
+    BitOr,  // |
+    BitXor, // xor
+    Shl,    // <<
+    Shr,    // >>
+}
+
+impl BinaryOp {
+    pub fn from_char(ch: char) -> Option<BinaryOp> {
+        match ch {
+            '+' => Some(BinaryOp::Add),
+            '-' => Some(BinaryOp::Sub),
+            '*' => Some(BinaryOp::Mul),
+            '/' => Some(BinaryOp::Div),
+            '^' => Some(BinaryOp::Pow),
+            '&' => Some(BinaryOp::BitAnd),
+            '|' => Some(BinaryOp::BitOr),
+            '<' => Some(BinaryOp::Shl),
+            '>' => Some(BinaryOp::Shr),
+            _ => None,
+        }
+    }
+
+    // 优先级：数值越大结合越紧，| < xor < & < 移位 < 加减 < 乘除 < 一元运算 < ^
+    fn precedence(self) -> u8 {
+        match self {
+            BinaryOp::BitOr => 1,
+            BinaryOp::BitXor => 2,
+            BinaryOp::BitAnd => 3,
+            BinaryOp::Shl | BinaryOp::Shr => 4,
+            BinaryOp::Add | BinaryOp::Sub => 5,
+            BinaryOp::Mul | BinaryOp::Div => 6,
+            BinaryOp::Pow => 8,
+        }
+    }
+
+    pub fn is_bitwise(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor | BinaryOp::Shl | BinaryOp::Shr
+        )
+    }
+
+    // 只有幂运算是右结合的：2^3^2 = 2^(3^2)
+    fn is_right_associative(self) -> bool {
+        self == BinaryOp::Pow
+    }
+
+    // 显示用的运算符
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Pow => "^",
+            BinaryOp::BitAnd => "&",
+            BinaryOp::BitOr => "|",
+            BinaryOp::BitXor => XOR_KEYWORD,
+            BinaryOp::Shl => "<<",
+            BinaryOp::Shr => ">>",
+        }
+    }
+}
+
+// 一元负号和按位取反的优先级介于乘除和幂之间：-2^2 = -(2^2)，2*-3 = 2*(-3)
+const UNARY_PRECEDENCE: u8 = 7;
+
+// 省略的乘号比乘除结合得更紧：5 km / 250 m = (5 km) / (250 m)
+const IMPLICIT_MUL_PRECEDENCE: u8 = 7;
+
+// 按位异或运算符：0b1100 xor 0b1010
+const XOR_KEYWORD: &str = "xor";
+
+// 单位换算运算符：60 mph to m/s
+const CONVERT_KEYWORD: &str = "to";
+
+// 括号、一元运算和右结合的幂最多嵌套的层数，防止递归下降耗尽栈空间
+const MAX_DEPTH: usize = 256;
+
+// 语法树（练习递归枚举）
+// 数字保留字面量原文，由计算模式决定解析成浮点数还是精确有理数
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(String),
+    Variable(String),
+    Neg(Box<Expr>),
+    BitNot(Box<Expr>), // 按位取反 ~x
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    Convert(Box<Expr>, Box<Expr>), // 单位换算：值 to 单位
+    Equation(Box<Expr>, Box<Expr>), // 方程：左边 = 右边，只能作为函数参数，例如 solve(x^2 = 2, x, 1)
+    List(Vec<Expr>),                // 列表：[1, 4, 9, 16]
+}
+
+impl Expr {
+    // 显示时的优先级，和解析时一致；数字、变量和函数调用不需要括号
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Number(_) | Expr::Variable(_) | Expr::Call(..) | Expr::List(_) => u8::MAX,
+            Expr::Neg(_) | Expr::BitNot(_) => UNARY_PRECEDENCE,
+            Expr::Binary(op, ..) => op.precedence(),
+            Expr::Convert(..) | Expr::Equation(..) => 0,
+        }
+    }
+}
+
+// 把子表达式写出来，优先级低于 min_precedence 时加括号
+fn write_operand(f: &mut fmt::Formatter, expr: &Expr, min_precedence: u8) -> fmt::Result {
+    if expr.precedence() < min_precedence {
+        write!(f, "({})", expr)
+    } else {
+        write!(f, "{}", expr)
+    }
+}
+
+// 写出逗号分隔的参数或列表元素
+fn write_list(f: &mut fmt::Formatter, exprs: &[Expr]) -> fmt::Result {
+    for (index, expr) in exprs.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", expr)?;
+    }
+    Ok(())
+}
+
+// 把语法树写回表达式，只在必要时加括号：x^2 * sin(x) + 1
+// 输出可以被 parse 重新解析成同样的语法树
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Number(text) | Expr::Variable(text) => write!(f, "{}", text),
+            Expr::Neg(operand) => {
+                write!(f, "-")?;
+                write_operand(f, operand, UNARY_PRECEDENCE + 1)
+            },
+            Expr::BitNot(operand) => {
+                write!(f, "~")?;
+                write_operand(f, operand, UNARY_PRECEDENCE + 1)
+            },
+            Expr::Binary(op, lhs, rhs) => {
+                let precedence = op.precedence();
+                // 左结合的运算符右边同级时要加括号：a - (b - c)；幂运算相反：(a^b)^c
+                let (lhs_min, rhs_min) = if op.is_right_associative() {
+                    (precedence + 1, precedence)
+                } else {
+                    (precedence, precedence + 1)
+                };
+                write_operand(f, lhs, lhs_min)?;
+                match op {
+                    BinaryOp::Pow => write!(f, "^")?,
+                    _ => write!(f, " {} ", op.symbol())?,
+                }
+                write_operand(f, rhs, rhs_min)
+            },
+            Expr::Call(name, args) => {
+                write!(f, "{}(", name)?;
+                write_list(f, args)?;
+                write!(f, ")")
+            },
+            Expr::List(items) => {
+                write!(f, "[")?;
+                write_list(f, items)?;
+                write!(f, "]")
+            },
+            Expr::Convert(value, unit) => {
+                write_operand(f, value, 1)?;
+                write!(f, " {} ", CONVERT_KEYWORD)?;
+                write_operand(f, unit, 1)
+            },
+            Expr::Equation(lhs, rhs) => write!(f, "{} = {}", lhs, rhs),
+        }
+    }
+}
+
+// 解析错误，记录出错的列号
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub column: usize,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>, column: usize) -> Self {
+        ParseError {
+            message: message.into(),
+            column,
+        }
+    }
+
+    // 生成指向出错位置的提示，例如：
+    //   3 + * 2
+    //       ^
+    pub fn pointer(&self, input: &str) -> String {
+        let padding = " ".repeat(self.column.saturating_sub(1));
+        format!("  {}\n  {}^", input, padding)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "第 {} 列: {}", self.column, self.message)
+    }
+}
+
+// 判断是否是合法的标识符（变量名）
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_alphabetic() || first == '_' => {
+            chars.all(|ch| ch.is_alphanumeric() || ch == '_')
+        },
+        _ => false,
+    }
+}
+
+// 拆分函数签名 "f(x, y)"，返回函数名和参数名（不检查名称是否合法）
+pub fn split_signature(signature: &str) -> Option<(String, Vec<String>)> {
+    let (name, params) = signature.trim().strip_suffix(')')?.split_once('(')?;
+    let params = if params.trim().is_empty() {
+        Vec::new()
+    } else {
+        params.split(',').map(|param| param.trim().to_string()).collect()
+    };
+    Some((name.trim().to_string(), params))
+}
+
+// 解析 0x/0b/0o 前缀的整数字面量，超过 64 位时返回 None
+// 结果按 64 位无符号数解析，0xFFFFFFFFFFFFFFFF 在 programmer 模式下就是 -1
+pub fn parse_radix_literal(text: &str) -> Option<u64> {
+    let radix = match text.get(..2)? {
+        "0x" => 16,
+        "0b" => 2,
+        "0o" => 8,
+        _ => return None,
+    };
+    u64::from_str_radix(&text[2..], radix).ok()
+}
+
+// 词法分析：把输入切分成词法单元
+fn tokenize(input: &str) -> Result<Vec<Spanned>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        let column = i + 1;
+
+        if ch.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // 0x1F、0b1010、0o17 形式的整数字面量
+        if ch == '0' {
+            let radix = match chars.get(i + 1) {
+                Some('x') => Some(16),
+                Some('b') => Some(2),
+                Some('o') => Some(8),
+                _ => None,
+            };
+            if radix.is_some_and(|radix| chars.get(i + 2).is_some_and(|ch| ch.is_digit(radix))) {
+                let start = i;
+                i += 2;
+                while i < chars.len() && chars[i].is_alphanumeric() {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                if parse_radix_literal(&text).is_none() {
+                    return Err(ParseError::new(format!("无效的整数字面量: {}", text), column));
+                }
+                tokens.push(Spanned { token: Token::Number(text), column });
+                continue;
+            }
+        }
+
+        if ch.is_ascii_digit() || ch == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            // 科学计数法：只有 e 后面跟着数字（可带符号）时才算数字的一部分
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                } else if !chars.get(i + 1).is_some_and(|ch| ch.is_alphanumeric() || *ch == '_') {
+                    // 1e、2e-：不当作 1·e，以免把写了一半的科学计数法算成别的值；2exp(1) 仍然是省略乘号
+                    let text: String = chars[start..j].iter().collect();
+                    return Err(ParseError::new(format!("科学计数法缺少指数: {}", text), i + 1));
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            if text.parse::<f64>().is_err() {
+                return Err(ParseError::new(format!("无效的数字: {}", text), column));
+            }
+            tokens.push(Spanned { token: Token::Number(text), column });
+            continue;
+        }
+
+        if ch.is_alphabetic() || ch == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let name: String = chars[start..i].iter().collect();
+            tokens.push(Spanned { token: Token::Ident(name), column });
+            continue;
+        }
+
+        // 移位运算符由两个字符组成，用第一个字符表示：<< 记为 '<'，>> 记为 '>'
+        if (ch == '<' || ch == '>') && chars.get(i + 1) == Some(&ch) {
+            tokens.push(Spanned { token: Token::Operator(ch), column });
+            i += 2;
+            continue;
+        }
+
+        let token = match ch {
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            '+' | '-' | '*' | '/' | '^' | '&' | '|' | '~' => Token::Operator(ch),
+            _ => return Err(ParseError::new(format!("无法识别的字符: '{}'", ch), column)),
+        };
+        tokens.push(Spanned { token, column });
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+// 语法分析器（练习结构体 + 方法 + 递归）
+struct Parser {
+    tokens: Vec<Spanned>,
+    position: usize,
+    end_column: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|spanned| &spanned.token)
+    }
+
+    // 当前词法单元的列号；到达末尾时指向输入结束后的位置
+    fn column(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map(|spanned| spanned.column)
+            .unwrap_or(self.end_column)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    // 下一个二元运算符和它的优先级；数字后面直接跟标识符时视为省略了乘号：5 km、2 pi
+    fn peek_binary_op(&self) -> Option<(BinaryOp, u8)> {
+        match self.peek() {
+            Some(Token::Operator(ch)) => BinaryOp::from_char(*ch).map(|op| (op, op.precedence())),
+            Some(Token::Ident(name)) if name == XOR_KEYWORD => Some((BinaryOp::BitXor, BinaryOp::BitXor.precedence())),
+            Some(Token::Ident(name)) if name != CONVERT_KEYWORD && self.follows_number() => {
+                Some((BinaryOp::Mul, IMPLICIT_MUL_PRECEDENCE))
+            },
+            _ => None,
+        }
+    }
+
+    // 上一个词法单元是否是数字
+    fn follows_number(&self) -> bool {
+        self.position > 0 && matches!(self.tokens[self.position - 1].token, Token::Number(_))
+    }
+
+    // 所有递归都经过这里，在这里统计嵌套层数
+    fn parse_expression(&mut self, min_precedence: u8) -> Result<Expr, ParseError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ParseError::new("嵌套太深", self.column()));
+        }
+        self.depth += 1;
+        let result = self.parse_binary(min_precedence);
+        self.depth -= 1;
+        result
+    }
+
+    // 优先级爬升：只消费优先级不低于 min_precedence 的运算符
+    fn parse_binary(&mut self, min_precedence: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_unary()?;
+
+        while let Some((op, precedence)) = self.peek_binary_op() {
+            if precedence < min_precedence {
+                break;
+            }
+            // 省略的乘号没有对应的词法单元
+            if op == BinaryOp::BitXor || matches!(self.peek(), Some(Token::Operator(_))) {
+                self.advance();
+            }
+
+            let next_min = if op.is_right_associative() {
+                precedence
+            } else {
+                precedence + 1
+            };
+            let rhs = self.parse_expression(next_min)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        // to 的优先级最低，只在最外层（或括号、参数内部的最外层）出现
+        while min_precedence == 0 && self.peek() == Some(&Token::Ident(CONVERT_KEYWORD.to_string())) {
+            self.advance();
+            let unit = self.parse_expression(1)?;
+            lhs = Expr::Convert(Box::new(lhs), Box::new(unit));
+        }
+
+        Ok(lhs)
+    }
+
+    // 一元运算：负号、正号和按位取反
+    fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Some(Token::Operator('~')) => {
+                self.advance();
+                let operand = self.parse_expression(UNARY_PRECEDENCE)?;
+                Ok(Expr::BitNot(Box::new(operand)))
+            },
+            Some(Token::Operator('-')) => {
+                self.advance();
+                let operand = self.parse_expression(UNARY_PRECEDENCE)?;
+                Ok(Expr::Neg(Box::new(operand)))
+            },
+            Some(Token::Operator('+')) => {
+                self.advance();
+                self.parse_expression(UNARY_PRECEDENCE)
+            },
+            _ => self.parse_primary(),
+        }
+    }
+
+    // 基本单元：数字、变量、函数调用、括号表达式、列表
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let column = self.column();
+        match self.advance() {
+            Some(Token::Number(value)) => Ok(Expr::Number(value)),
+            Some(Token::Ident(name)) => {
+                if self.peek() == Some(&Token::LParen) {
+                    self.advance();
+                    let args = self.parse_arguments(&name, column)?;
+                    Ok(Expr::Call(name, args))
+                } else {
+                    Ok(Expr::Variable(name))
+                }
+            },
+            Some(Token::LParen) => {
+                let inner = self.parse_expression(0)?;
+                match self.peek() {
+                    Some(Token::RParen) => {
+                        self.advance();
+                        Ok(inner)
+                    },
+                    _ => Err(ParseError::new(
+                        format!("缺少右括号（与第 {} 列的左括号匹配）", column),
+                        self.column(),
+                    )),
+                }
+            },
+            Some(Token::LBracket) => self.parse_list(column),
+            Some(Token::RParen) => Err(ParseError::new("多余的右括号", column)),
+            Some(Token::RBracket) => Err(ParseError::new("多余的右方括号", column)),
+            Some(Token::Comma) => Err(ParseError::new("逗号只能出现在函数参数或列表元素之间", column)),
+            Some(Token::Equals) => Err(ParseError::new("等号缺少左边的表达式", column)),
+            Some(Token::Operator(op)) => {
+                // 移位运算符在词法单元中只记录了第一个字符
+                let text = match op {
+                    '<' | '>' => format!("{}{}", op, op),
+                    _ => op.to_string(),
+                };
+                Err(ParseError::new(format!("运算符 '{}' 缺少左操作数", text), column))
+            },
+            None => Err(ParseError::new("表达式不完整", column)),
+        }
+    }
+
+    // 解析列表 [1, 2, 3]，左方括号已经被消费
+    fn parse_list(&mut self, column: usize) -> Result<Expr, ParseError> {
+        let mut items = Vec::new();
+        if self.peek() == Some(&Token::RBracket) {
+            self.advance();
+            return Ok(Expr::List(items));
+        }
+
+        loop {
+            items.push(self.parse_expression(0)?);
+            match self.peek() {
+                Some(Token::Comma) => {
+                    self.advance();
+                },
+                Some(Token::RBracket) => {
+                    self.advance();
+                    return Ok(Expr::List(items));
+                },
+                _ => {
+                    return Err(ParseError::new(
+                        format!("缺少右方括号（与第 {} 列的左方括号匹配）", column),
+                        self.column(),
+                    ))
+                },
+            }
+        }
+    }
+
+    // 解析函数参数列表，左括号已经被消费
+    fn parse_arguments(&mut self, name: &str, column: usize) -> Result<Vec<Expr>, ParseError> {
+        let mut args = Vec::new();
+        if self.peek() == Some(&Token::RParen) {
+            self.advance();
+            return Ok(args);
+        }
+
+        loop {
+            let mut arg = self.parse_expression(0)?;
+            // 参数可以是方程：solve(x^2 = 2, x, 1)
+            if self.peek() == Some(&Token::Equals) {
+                self.advance();
+                let rhs = self.parse_expression(0)?;
+                arg = Expr::Equation(Box::new(arg), Box::new(rhs));
+            }
+            args.push(arg);
+            match self.peek() {
+                Some(Token::Comma) => {
+                    self.advance();
+                },
+                Some(Token::RParen) => {
+                    self.advance();
+                    return Ok(args);
+                },
+                _ => {
+                    return Err(ParseError::new(
+                        format!("函数 {} 的参数列表缺少右括号（第 {} 列开始）", name, column),
+                        self.column(),
+                    ))
+                },
+            }
+        }
+    }
+}
+
+// 解析完整的表达式
+pub fn parse(input: &str) -> Result<Expr, ParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        end_column: input.chars().count() + 1,
+        depth: 0,
+    };
+
+    let expr = parser.parse_expression(0)?;
+
+    // 所有词法单元都应该被消费掉
+    match parser.peek() {
+        None => Ok(expr),
+        Some(Token::RParen) => Err(ParseError::new("多余的右括号", parser.column())),
+        Some(Token::RBracket) => Err(ParseError::new("多余的右方括号", parser.column())),
+        Some(Token::Comma) => Err(ParseError::new("逗号只能出现在函数参数或列表元素之间", parser.column())),
+        Some(Token::Equals) => Err(ParseError::new("等号只能出现在函数参数中，例如 solve(x^2 = 2, x, 1)", parser.column())),
+        Some(_) => Err(ParseError::new("缺少运算符", parser.column())),
+    }
+}
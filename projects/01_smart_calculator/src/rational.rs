@@ -0,0 +1,227 @@
+// 精确有理数 - 分子分母都是任意精度整数
+// 学习目标：结构体不变量、运算符重载、Ord/Display trait
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::bigint::BigInt;
+
+// 转换成 f64 时保留的有效位数，超出部分先右移掉避免溢出成无穷大
+const F64_SCALE_BITS: u64 = 1000;
+
+// 不变量：分母为正，且分子分母互质
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rational {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl Rational {
+    // 创建并约分，分母为零时返回 None
+    pub fn new(numer: BigInt, denom: BigInt) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+        let divisor = numer.gcd(&denom);
+        let (mut numer, _) = numer.div_rem(&divisor)?;
+        let (mut denom, _) = denom.div_rem(&divisor)?;
+        if denom.is_negative() {
+            numer = -numer;
+            denom = -denom;
+        }
+        Some(Rational { numer, denom })
+    }
+
+    pub fn from_integer(value: BigInt) -> Self {
+        Rational {
+            numer: value,
+            denom: BigInt::one(),
+        }
+    }
+
+    // 解析十进制小数，例如 "0.1"、"-2.5e-3"，结果精确等于字面值
+    pub fn parse_decimal(text: &str) -> Option<Self> {
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(index) => (&text[..index], text[index + 1..].parse::<i32>().ok()?),
+            None => (text, 0),
+        };
+        let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if fraction_part.starts_with(['+', '-']) {
+            return None;
+        }
+
+        let (negative, integer_digits) = match integer_part.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, integer_part.strip_prefix('+').unwrap_or(integer_part)),
+        };
+        if integer_digits.is_empty() && fraction_part.is_empty() {
+            return None;
+        }
+
+        // 把 "12.34" 看成 1234 / 10^2，再乘上 10^exponent
+        let digits = format!("{}{}", integer_digits, fraction_part);
+        let mut numer = BigInt::parse(&digits)?;
+        if negative {
+            numer = -numer;
+        }
+        let scale = exponent - fraction_part.len() as i32;
+        let ten = BigInt::from_u64(10);
+        if scale >= 0 {
+            Some(Rational::from_integer(&numer * &ten.pow(scale as u32)))
+        } else {
+            Rational::new(numer, ten.pow(scale.unsigned_abs()))
+        }
+    }
+
+    // 把浮点数转换为它的最短十进制表示对应的有理数（0.1 -> 1/10）
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        Rational::parse_decimal(&value.to_string())
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &BigInt {
+        &self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numer.is_negative()
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom.clone(),
+        }
+    }
+
+    pub fn neg(&self) -> Rational {
+        Rational {
+            numer: -self.numer.clone(),
+            denom: self.denom.clone(),
+        }
+    }
+
+    pub fn add(&self, other: &Rational) -> Rational {
+        let numer = &(&self.numer * &other.denom) + &(&other.numer * &self.denom);
+        let denom = &self.denom * &other.denom;
+        Rational::new(numer, denom).expect("分母不为零")
+    }
+
+    pub fn sub(&self, other: &Rational) -> Rational {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Rational) -> Rational {
+        let numer = &self.numer * &other.numer;
+        let denom = &self.denom * &other.denom;
+        Rational::new(numer, denom).expect("分母不为零")
+    }
+
+    // 除以零时返回 None
+    pub fn div(&self, other: &Rational) -> Option<Rational> {
+        let numer = &self.numer * &other.denom;
+        let denom = &self.denom * &other.numer;
+        Rational::new(numer, denom)
+    }
+
+    // 整数次幂，0 的负数次幂返回 None
+    pub fn pow(&self, exponent: i64) -> Option<Rational> {
+        let power = u32::try_from(exponent.unsigned_abs()).ok()?;
+        let numer = self.numer.pow(power);
+        let denom = self.denom.pow(power);
+        if exponent >= 0 {
+            Rational::new(numer, denom)
+        } else {
+            Rational::new(denom, numer)
+        }
+    }
+
+    // 向下取整
+    pub fn floor(&self) -> BigInt {
+        let (quotient, remainder) = self.numer.div_rem(&self.denom).expect("分母不为零");
+        if remainder.is_negative() {
+            &quotient - &BigInt::one()
+        } else {
+            quotient
+        }
+    }
+
+    // 向上取整
+    pub fn ceil(&self) -> BigInt {
+        -self.neg().floor()
+    }
+
+    // 四舍五入（.5 远离零）
+    pub fn round(&self) -> BigInt {
+        let half = Rational::new(BigInt::one(), BigInt::from_u64(2)).expect("分母不为零");
+        if self.is_negative() {
+            -self.neg().add(&half).floor()
+        } else {
+            self.add(&half).floor()
+        }
+    }
+
+    // 有理数的平方根恰好也是有理数时返回 Some
+    pub fn sqrt_exact(&self) -> Option<Rational> {
+        let numer_root = self.numer.sqrt_floor()?;
+        let denom_root = self.denom.sqrt_floor()?;
+        if &numer_root * &numer_root == self.numer && &denom_root * &denom_root == self.denom {
+            Rational::new(numer_root, denom_root)
+        } else {
+            None
+        }
+    }
+
+    // 转换为最接近的 f64
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.numer.bits().max(self.denom.bits());
+        if bits <= F64_SCALE_BITS {
+            return self.numer.to_f64() / self.denom.to_f64();
+        }
+        // 分子分母同时右移，保持比值大致不变
+        let shift = bits - F64_SCALE_BITS;
+        let numer = self.numer.shr(shift).to_f64();
+        let denom = self.denom.shr(shift).to_f64();
+        if denom == 0.0 {
+            // 分母远小于分子：结果溢出
+            return if self.is_negative() { f64::NEG_INFINITY } else { f64::INFINITY };
+        }
+        numer / denom
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numer * &other.denom).cmp(&(&other.numer * &self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// JSON 行协议 - calculator --serve 从标准输入逐行读取请求，每个请求输出一行响应
+// 学习目标：协议设计、把内部类型转换为外部格式、逐行处理输入输出
+//
+// 请求是一行 JSON 对象：
+//   {"id": 1, "eval": "2+2"}     执行一行输入（表达式、赋值或命令），和交互模式的写法相同
+//   {"id": 2, "reset": true}     丢弃变量、函数、历史和设置，回到初始状态
+// id 可以是任意 JSON 值，响应中原样返回；省略时响应中也没有 id。
+//
+// 响应（每行一个 JSON 对象，顺序和请求相同）：
+//   {"id":1,"result":4}                               计算结果
+//   {"id":2,"reset":true}                             reset 完成
+//   {"id":3,"result":"1/3"}                           不能用 JSON 数字表示的结果是显示文本：分数、带单位的数值、复数
+//   {"id":4,"result":[1,2,3]}                         列表是数组
+//   {"id":5,"result":1.4142135623730951,"report":"…"} solve 和 integrate 同时给出收敛情况
+//   {"id":6,"expression":"2 * x"}                     diff、simplify 的结果
+//   {"id":7,"message":"变量 x 设置为: 5"}             赋值和设置命令的提示
+//   {"id":8,"lines":["=== 存储的变量 ===", "x = 5"]}  vars、history 等列表
+//   {"id":9}                                          空输入或注释
+//   {"id":10,"error":{"kind":"parse","message":"第 3 列: 表达式不完整","column":3}}
+// 请求本身有问题（不是 JSON、缺少 eval）时 kind 是 "protocol"。
+// eval "quit" 时输出 {"id":…,"quit":true} 后结束；标准输入结束时也会结束。
+// 变量、函数和设置在同一个进程的请求之间保留，不读取也不自动保存会话文件。
+
+use std::io::{self, BufRead, Write};
+
+use crate::calculator::Calculator;
+use crate::commands::{execute, Reply};
+use crate::error::CalcError;
+use crate::json::{self, Json};
+use crate::number::Number;
+use crate::value::Value;
+
+// 协议错误的种类
+const PROTOCOL_ERROR: &str = "protocol";
+
+// 处理一个请求后的动作
+pub enum Outcome {
+    Continue(String), // 输出响应，继续读取
+    Quit(String),     // 输出响应后结束
+}
+
+// 一个客户端的会话：请求之间共享同一个计算器
+pub struct Server {
+    calculator: Calculator,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Server::new()
+    }
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Server {
+            calculator: Calculator::new(),
+        }
+    }
+
+    // 处理一行请求，返回一行响应（不含换行符）
+    pub fn handle_line(&mut self, line: &str) -> Outcome {
+        let request = match json::parse(line) {
+            Ok(request @ Json::Object(_)) => request,
+            Ok(_) => return Outcome::Continue(protocol_error(None, "请求必须是 JSON 对象")),
+            Err(error) => return Outcome::Continue(protocol_error(None, &format!("无效的 JSON: {}", error))),
+        };
+        let id = request.get("id").cloned();
+
+        match (request.get("eval"), request.get("reset")) {
+            (Some(_), Some(_)) => Outcome::Continue(protocol_error(id, "eval 和 reset 不能出现在同一个请求中")),
+            (None, Some(Json::Bool(true))) => {
+                self.calculator = Calculator::new();
+                Outcome::Continue(response(id, vec![("reset", Json::Bool(true))]))
+            },
+            (None, Some(_)) => Outcome::Continue(protocol_error(id, "reset 的值必须是 true")),
+            (Some(Json::String(input)), None) if input.contains(['\n', '\r']) => {
+                Outcome::Continue(protocol_error(id, "eval 只能包含一行输入"))
+            },
+            (Some(Json::String(input)), None) => self.evaluate(id, input),
+            (Some(_), None) => Outcome::Continue(protocol_error(id, "eval 的值必须是字符串")),
+            (None, None) => Outcome::Continue(protocol_error(id, "请求需要 eval 或 reset 字段")),
+        }
+    }
+
+    // 执行一行输入，把结果转换为响应
+    fn evaluate(&mut self, id: Option<Json>, input: &str) -> Outcome {
+        let fields = match execute(&mut self.calculator, input) {
+            Ok(Reply::Result(value)) => vec![("result", self.value_json(&value))],
+            Ok(Reply::Report(value, report)) => {
+                vec![("result", self.value_json(&value)), ("report", Json::string(report))]
+            },
+            Ok(Reply::Expression(expr)) => vec![("expression", Json::string(expr.to_string()))],
+            Ok(Reply::Message(message)) => vec![("message", Json::string(message))],
+            Ok(Reply::Listing(lines)) => vec![("lines", Json::Array(lines.into_iter().map(Json::String).collect()))],
+            Ok(Reply::Nothing) => Vec::new(),
+            Ok(Reply::Quit) => return Outcome::Quit(response(id, vec![("quit", Json::Bool(true))])),
+            Err(error) => vec![("error", error_json(&error))],
+        };
+        Outcome::Continue(response(id, fields))
+    }
+
+    // 结果的 JSON 表示：浮点数和精确整数是数字，列表是数组，其他值是显示文本
+    fn value_json(&self, value: &Value) -> Json {
+        match value {
+            Value::Number(Number::Float(value)) => Json::number(*value),
+            Value::Number(Number::Exact(exact)) if exact.is_integer() => Json::Number(exact.numer().to_string()),
+            Value::List(items) => Json::Array(items.iter().map(|item| self.value_json(item)).collect()),
+            _ => Json::string(self.calculator.format_value(value)),
+        }
+    }
+}
+
+// 组装响应对象，id 在最前面
+fn response(id: Option<Json>, fields: Vec<(&str, Json)>) -> String {
+    let mut object: Vec<(String, Json)> = id.map(|id| ("id".to_string(), id)).into_iter().collect();
+    object.extend(fields.into_iter().map(|(key, value)| (key.to_string(), value)));
+    Json::Object(object).to_string()
+}
+
+// 计算错误：语法错误附带列号，消息中不包含指向出错位置的多行提示
+fn error_json(error: &CalcError) -> Json {
+    let mut fields = vec![("kind".to_string(), Json::string(error.kind()))];
+    match error {
+        CalcError::Parse { error, .. } => {
+            fields.push(("message".to_string(), Json::string(error.to_string())));
+            fields.push(("column".to_string(), Json::Number(error.column.to_string())));
+        },
+        error => fields.push(("message".to_string(), Json::string(error.to_string()))),
+    }
+    Json::Object(fields)
+}
+
+fn protocol_error(id: Option<Json>, message: &str) -> String {
+    let error = Json::Object(vec![
+        ("kind".to_string(), Json::string(PROTOCOL_ERROR)),
+        ("message".to_string(), Json::string(message)),
+    ]);
+    response(id, vec![("error", error)])
+}
+
+// 逐行处理请求直到输入结束或收到 quit；每个响应后立即刷新，调用方可以一问一答
+pub fn run(mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut server = Server::new();
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        if input.read_until(b'\n', &mut buffer)? == 0 {
+            break;
+        }
+        // 按字节读取：一行不是有效的 UTF-8 时只拒绝这个请求，继续处理后面的请求
+        let outcome = match std::str::from_utf8(&buffer) {
+            Ok(line) => {
+                let line = line.trim_end_matches('\n').trim_end_matches('\r');
+                if line.trim().is_empty() {
+                    continue;
+                }
+                server.handle_line(line)
+            },
+            Err(_) => Outcome::Continue(protocol_error(None, "请求不是有效的 UTF-8")),
+        };
+        let (text, quit) = match outcome {
+            Outcome::Continue(text) => (text, false),
+            Outcome::Quit(text) => (text, true),
+        };
+        writeln!(output, "{}", text)?;
+        output.flush()?;
+        if quit {
+            break;
+        }
+    }
+    Ok(())
+}
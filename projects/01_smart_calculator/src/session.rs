@@ -0,0 +1,479 @@
+// 会话持久化 - 把变量、函数和历史保存到文本文件，下次启动时恢复
+// 学习目标：文件 I/O、字符串解析、错误处理、版本兼容
+//
+// 会话文件格式（纯文本，UTF-8，每行一条记录）：
+//
+//   # smart_calculator session        以 # 开头的行和空行会被忽略
+//   version 8                         必须是第一条记录，版本号比程序支持的新时拒绝加载
+//   mode exact                        计算模式：float、exact 或 programmer
+//   word 32                           programmer 模式的字长：8、16、32 或 64
+//   base hex                          programmer 模式的显示进制：dec、hex、bin 或 oct
+//   tolerance 1e-10                   solve、integrate 的收敛容差
+//   iterations 100                    solve 的最大迭代次数
+//   complex on                        是否开启复数运算：on 或 off
+//   complex_display polar             复数的显示方式：rect 或 polar
+//   format sig 6                      记数法：auto、fix N、sig N、sci N 或 eng N
+//   format_separators on              是否显示千位分隔符：on 或 off
+//   format_fractions mixed            精确的非整数的显示方式：fraction、mixed 或 decimal
+//   value q:3/10                      当前值
+//   var x q:1/7                       变量：名称 + 数值
+//   var data [q:1,q:4,f:2.5]          列表变量：元素之间用逗号分隔，不含空格
+//   func f(x, y) = x^2 + y            自定义函数：与输入时的写法相同
+//   history op q:1 + q:2 q:3          历史记录，按时间顺序：
+//                                       op <之前的值> <运算符> <操作数> <结果>
+//   history expr f:0 f:7 3 + 4        expr <之前的值> <结果> <表达式原文>
+//
+// 数值编码：f:<浮点数> 表示浮点数，q:<分子>/<分母> 或 q:<整数> 表示精确有理数，
+// 带单位的数值在后面加 @单位，例如 f:5.3@km、q:1/2@m/s^2；列表写成 [元素,元素]；
+// 复数写成 c:<实部>;<虚部>，例如 c:3;-4。
+//
+// 版本历史：
+//   1  历史记录保存为显示用的文本，例如 "history 1 + 2 = 3"；加载时会尽量转换成结构化记录
+//   2  历史记录改为结构化格式，支持撤销和回放
+//   3  数值可以带单位
+//   4  增加 programmer 模式的字长和显示进制
+//   5  增加数值方法的容差和迭代次数
+//   6  数值可以是列表
+//   7  增加复数和复数设置
+//   8  增加输出格式设置
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::bigint::BigInt;
+use crate::calculator::Calculator;
+use crate::complex::{Complex, ComplexSettings};
+use crate::error::CalcError;
+use crate::format::{FormatSettings, FractionStyle, Notation};
+use crate::history::{HistoryEntry, Operation};
+use crate::number::{self, Base, Number, NumberMode};
+use crate::numeric::NumericSettings;
+use crate::parser;
+use crate::rational::Rational;
+use crate::units;
+use crate::value::Value;
+
+// 当前程序写出的会话格式版本
+pub const SESSION_VERSION: u32 = 8;
+
+// 自动保存的文件名（位于用户主目录）
+const DEFAULT_FILE_NAME: &str = ".smart_calculator_session";
+
+// 可以用环境变量指定自动保存的位置，设置为空字符串时关闭自动保存
+const PATH_ENV_VAR: &str = "SMART_CALCULATOR_SESSION";
+
+// 一个自定义函数的定义
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDefinition {
+    pub name: String,
+    pub params: Vec<String>,
+    pub body: String,
+}
+
+// 会话中需要持久化的全部状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub mode: NumberMode,
+    pub word_bits: u32,
+    pub base: Base,
+    pub numeric: NumericSettings,
+    pub complex: ComplexSettings,
+    pub format: FormatSettings,
+    pub value: Value,
+    pub variables: Vec<(String, Value)>,
+    pub functions: Vec<FunctionDefinition>,
+    pub history: Vec<HistoryEntry>,
+}
+
+impl Session {
+    // 序列化为会话文件内容
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            "# smart_calculator session".to_string(),
+            format!("version {}", SESSION_VERSION),
+            format!("mode {}", self.mode.name()),
+            format!("word {}", self.word_bits),
+            format!("base {}", self.base.name()),
+            format!("tolerance {:e}", self.numeric.tolerance),
+            format!("iterations {}", self.numeric.max_iterations),
+            format!("complex {}", if self.complex.enabled { "on" } else { "off" }),
+            format!("complex_display {}", if self.complex.polar { "polar" } else { "rect" }),
+            format!("format {}", self.format.notation.name()),
+            format!("format_separators {}", if self.format.separators { "on" } else { "off" }),
+            format!("format_fractions {}", self.format.fractions.name()),
+            format!("value {}", encode_value(&self.value)),
+        ];
+        for (name, value) in &self.variables {
+            lines.push(format!("var {} {}", name, encode_value(value)));
+        }
+        for function in &self.functions {
+            lines.push(format!(
+                "func {}({}) = {}",
+                function.name,
+                function.params.join(", "),
+                function.body
+            ));
+        }
+        for entry in &self.history {
+            lines.push(format!("history {}", encode_entry(entry)));
+        }
+
+        let mut text = lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    // 解析会话文件内容，任何一行出错都会拒绝整个文件
+    pub fn parse(text: &str) -> Result<Session, CalcError> {
+        let mut session = Session {
+            mode: NumberMode::Float,
+            word_bits: 64,
+            base: Base::Dec,
+            numeric: NumericSettings::default(),
+            complex: ComplexSettings::default(),
+            format: FormatSettings::default(),
+            value: Value::zero(),
+            variables: Vec::new(),
+            functions: Vec::new(),
+            history: Vec::new(),
+        };
+        let mut version = None;
+        // 函数在读取时就按输入时的规则定义一遍，参数名重复、与内置函数同名等错误可以指出行号
+        let mut functions = Calculator::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, rest) = line.split_once(' ').unwrap_or((line, ""));
+            let error = |message: String| CalcError::Session(format!("会话文件第 {} 行: {}", line_number, message));
+
+            let Some(file_version) = version else {
+                if key != "version" {
+                    return Err(error("缺少版本号，文件可能已损坏".to_string()));
+                }
+                let version_number: u32 = rest
+                    .trim()
+                    .parse()
+                    .map_err(|_| error(format!("无效的版本号: {}", rest)))?;
+                if version_number > SESSION_VERSION {
+                    return Err(error(format!(
+                        "文件版本 {} 比当前程序支持的版本 {} 更新，请升级计算器",
+                        version_number, SESSION_VERSION
+                    )));
+                }
+                version = Some(version_number);
+                continue;
+            };
+
+            match key {
+                "mode" => {
+                    session.mode = NumberMode::from_name(rest.trim())
+                        .ok_or_else(|| error(format!("未知的计算模式: {}", rest)))?;
+                },
+                "word" => {
+                    session.word_bits = rest
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|bits| number::WORD_SIZES.contains(bits))
+                        .ok_or_else(|| error(format!("无效的字长: {}", rest)))?;
+                },
+                "base" => {
+                    session.base = Base::from_name(rest.trim())
+                        .ok_or_else(|| error(format!("未知的显示进制: {}", rest)))?;
+                },
+                "tolerance" => {
+                    session.numeric.tolerance = NumericSettings::parse_tolerance(rest)
+                        .ok_or_else(|| error(format!("无效的容差: {}", rest)))?;
+                },
+                "iterations" => {
+                    session.numeric.max_iterations = NumericSettings::parse_iterations(rest)
+                        .ok_or_else(|| error(format!("无效的迭代次数: {}", rest)))?;
+                },
+                "complex" => {
+                    session.complex.enabled = match rest.trim() {
+                        "on" => true,
+                        "off" => false,
+                        other => return Err(error(format!("无效的复数设置: {}", other))),
+                    };
+                },
+                "complex_display" => {
+                    session.complex.polar = match rest.trim() {
+                        "polar" => true,
+                        "rect" => false,
+                        other => return Err(error(format!("无效的复数显示方式: {}", other))),
+                    };
+                },
+                "format" => {
+                    session.format.notation = Notation::parse(rest)
+                        .ok_or_else(|| error(format!("无效的记数法: {}", rest)))?;
+                },
+                "format_separators" => {
+                    session.format.separators = match rest.trim() {
+                        "on" => true,
+                        "off" => false,
+                        other => return Err(error(format!("无效的千位分隔符设置: {}", other))),
+                    };
+                },
+                "format_fractions" => {
+                    session.format.fractions = FractionStyle::from_name(rest.trim())
+                        .ok_or_else(|| error(format!("无效的分数显示方式: {}", rest)))?;
+                },
+                "value" => {
+                    session.value = decode_value(rest.trim()).map_err(error)?;
+                },
+                "var" => {
+                    let (name, value) = rest
+                        .split_once(' ')
+                        .ok_or_else(|| error("变量记录缺少数值".to_string()))?;
+                    if !parser::is_identifier(name) {
+                        return Err(error(format!("无效的变量名: {}", name)));
+                    }
+                    let value = decode_value(value.trim()).map_err(error)?;
+                    session.variables.push((name.to_string(), value));
+                },
+                "func" => {
+                    let function = decode_function(rest).map_err(error)?;
+                    functions
+                        .define_function(&function.name, function.params.clone(), &function.body)
+                        .map_err(|cause| error(cause.to_string()))?;
+                    session.functions.push(function);
+                },
+                "history" => {
+                    let entry = if file_version == 1 {
+                        let previous = session.history.last().map(|entry| entry.result.clone());
+                        decode_legacy_entry(rest, previous, session.mode)
+                    } else {
+                        decode_entry(rest).map_err(error)?
+                    };
+                    session.history.push(entry);
+                },
+                "version" => return Err(error("重复的版本号".to_string())),
+                _ => return Err(error(format!("无法识别的记录: {}", key))),
+            }
+        }
+
+        if version.is_none() {
+            return Err(CalcError::Session("会话文件为空或缺少版本号".to_string()));
+        }
+        Ok(session)
+    }
+}
+
+// 保存会话到文件
+pub fn save(path: &str, session: &Session) -> Result<(), CalcError> {
+    fs::write(path, session.to_text())
+        .map_err(|error| CalcError::Session(format!("无法写入 {}: {}", path, error)))
+}
+
+// 从文件加载会话
+pub fn load(path: &str) -> Result<Session, CalcError> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| CalcError::Session(format!("无法读取 {}: {}", path, error)))?;
+    Session::parse(&text).map_err(|error| CalcError::Session(format!("{}: {}", path, error)))
+}
+
+// 自动保存文件的位置：环境变量优先，其次是用户主目录；返回 None 表示不自动保存
+pub fn default_path() -> Option<String> {
+    if let Ok(path) = env::var(PATH_ENV_VAR) {
+        return if path.is_empty() { None } else { Some(path) };
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let path: PathBuf = [home, DEFAULT_FILE_NAME.into()].iter().collect();
+    Some(path.to_string_lossy().into_owned())
+}
+
+fn encode_number(value: &Number) -> String {
+    match value {
+        Number::Float(value) => format!("f:{}", value),
+        Number::Exact(value) => format!("q:{}", value),
+    }
+}
+
+fn decode_number(text: &str) -> Result<Number, String> {
+    let invalid = || format!("无效的数值: {}", text);
+    if let Some(float) = text.strip_prefix("f:") {
+        return float.parse::<f64>().map(Number::Float).map_err(|_| invalid());
+    }
+    if let Some(exact) = text.strip_prefix("q:") {
+        let (numer, denom) = exact.split_once('/').unwrap_or((exact, "1"));
+        let numer = BigInt::parse(numer).ok_or_else(invalid)?;
+        let denom = BigInt::parse(denom).ok_or_else(invalid)?;
+        return Rational::new(numer, denom).map(Number::Exact).ok_or_else(invalid);
+    }
+    Err(invalid())
+}
+
+// 带单位的数值写成 <数值>@<单位>
+fn encode_value(value: &Value) -> String {
+    match value {
+        Value::Number(number) => encode_number(number),
+        Value::Quantity(quantity) => format!("{}@{}", encode_number(&quantity.magnitude), quantity.unit),
+        Value::Complex(z) => format!("c:{};{}", z.re, z.im),
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().map(encode_value).collect();
+            format!("[{}]", items.join(","))
+        },
+    }
+}
+
+fn decode_value(text: &str) -> Result<Value, String> {
+    if let Some(items) = text.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+        return split_list(items)
+            .into_iter()
+            .map(decode_value)
+            .collect::<Result<_, _>>()
+            .map(Value::List);
+    }
+    if let Some(parts) = text.strip_prefix("c:") {
+        let invalid = || format!("无效的复数: {}", text);
+        let (re, im) = parts.split_once(';').ok_or_else(invalid)?;
+        let (re, im) = (re.parse::<f64>().map_err(|_| invalid())?, im.parse::<f64>().map_err(|_| invalid())?);
+        return Ok(Value::complex(Complex::new(re, im)));
+    }
+    let Some((number, unit)) = text.split_once('@') else {
+        return decode_number(text).map(Value::Number);
+    };
+    let invalid = || format!("无效的单位: {}", unit);
+    let unit = parser::parse(unit).map_err(|_| invalid())?;
+    let unit = units::unit_from_expr(&unit).map_err(|_| invalid())?;
+    Ok(Value::quantity(decode_number(number)?, unit))
+}
+
+// 按最外层的逗号拆分列表元素，元素本身也可以是列表
+fn split_list(text: &str) -> Vec<&str> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&text[start..index]);
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    items.push(&text[start..]);
+    items
+}
+
+fn encode_entry(entry: &HistoryEntry) -> String {
+    match &entry.operation {
+        Operation::Apply { operator, operand } => format!(
+            "op {} {} {} {}",
+            encode_value(&entry.previous),
+            operator,
+            encode_value(operand),
+            encode_value(&entry.result)
+        ),
+        Operation::Evaluate(source) => format!(
+            "expr {} {} {}",
+            encode_value(&entry.previous),
+            encode_value(&entry.result),
+            source
+        ),
+    }
+}
+
+fn decode_entry(text: &str) -> Result<HistoryEntry, String> {
+    let invalid = || format!("无效的历史记录: {}", text);
+    let (kind, rest) = text.split_once(' ').ok_or_else(invalid)?;
+    match kind {
+        "op" => {
+            let fields: Vec<&str> = rest.split(' ').collect();
+            let [previous, operator, operand, result] = fields[..] else {
+                return Err(invalid());
+            };
+            let mut operator_chars = operator.chars();
+            let (Some(operator), None) = (operator_chars.next(), operator_chars.next()) else {
+                return Err(invalid());
+            };
+            Ok(HistoryEntry {
+                operation: Operation::Apply {
+                    operator,
+                    operand: decode_value(operand)?,
+                },
+                previous: decode_value(previous)?,
+                result: decode_value(result)?,
+            })
+        },
+        "expr" => {
+            let mut fields = rest.splitn(3, ' ');
+            let (Some(previous), Some(result), Some(source)) = (fields.next(), fields.next(), fields.next()) else {
+                return Err(invalid());
+            };
+            Ok(HistoryEntry {
+                operation: Operation::Evaluate(source.to_string()),
+                previous: decode_value(previous)?,
+                result: decode_value(result)?,
+            })
+        },
+        _ => Err(invalid()),
+    }
+}
+
+// 转换版本 1 的文本历史，例如 "1 + 2 = 3" 或 "sqrt(16) + 1 = 5"
+// 文本中没有记录表达式之前的值，就用上一条记录的结果代替
+fn decode_legacy_entry(text: &str, previous: Option<Value>, mode: NumberMode) -> HistoryEntry {
+    let parse = |text: &str| -> Option<Value> {
+        let number = match text.split_once('/') {
+            Some(_) => decode_number(&format!("q:{}", text)).ok(),
+            None => Number::parse_literal(text, mode),
+        };
+        number.map(Value::Number)
+    };
+
+    let (lhs, result) = text.rsplit_once(" = ").unwrap_or((text, ""));
+    let result = parse(result);
+    let fields: Vec<&str> = lhs.split(' ').collect();
+    if let ([left, operator, operand], Some(result)) = (&fields[..], &result) {
+        let mut operator_chars = operator.chars();
+        if let (Some(operator), None, Some(left), Some(operand)) =
+            (operator_chars.next(), operator_chars.next(), parse(left), parse(operand))
+        {
+            if "+-*/^".contains(operator) {
+                return HistoryEntry {
+                    operation: Operation::Apply { operator, operand },
+                    previous: left,
+                    result: result.clone(),
+                };
+            }
+        }
+    }
+
+    let previous = previous.unwrap_or_else(Value::zero);
+    HistoryEntry {
+        operation: Operation::Evaluate(lhs.to_string()),
+        result: result.unwrap_or_else(|| previous.clone()),
+        previous,
+    }
+}
+
+// 解析 "f(x, y) = x^2 + y"，同时检查函数体能否被解析
+fn decode_function(text: &str) -> Result<FunctionDefinition, String> {
+    let invalid = || format!("无效的函数定义: {}", text);
+    let (signature, body) = text.split_once('=').ok_or_else(invalid)?;
+    let (name, params) = parser::split_signature(signature).ok_or_else(invalid)?;
+    if !parser::is_identifier(&name) || !params.iter().all(|param| parser::is_identifier(param)) {
+        return Err(invalid());
+    }
+
+    let body = body.trim();
+    parser::parse(body).map_err(|error| format!("函数 {} 的函数体无效: {}", name, error))?;
+
+    Ok(FunctionDefinition {
+        name,
+        params,
+        body: body.to_string(),
+    })
+}
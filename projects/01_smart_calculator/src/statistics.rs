@@ -0,0 +1,200 @@
+// 统计函数 - 对列表求平均值、中位数、标准差、线性回归等
+// 学习目标：切片、排序、迭代器（fold、zip）、Option 与 Result 组合
+//
+// 统计函数只接受纯数值的列表；两个精确值运算结果仍然精确，mean([1, 2]) = 3/2。
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::bigint::BigInt;
+use crate::error::CalcError;
+use crate::number::Number;
+use crate::parser::BinaryOp;
+use crate::rational::Rational;
+use crate::value::Value;
+
+// 参数是列表的函数；sum、min、max 只有一个列表参数时也按统计函数计算
+pub const STATISTICS_FUNCTIONS: [&str; 10] = [
+    "count", "prod", "mean", "median", "mode", "variance", "stdev", "percentile", "linreg", "corr",
+];
+
+// 和其他函数同名、参数是一个列表时才按统计函数计算的函数：sum([1, 2, 3])、max(data)
+const LIST_OVERLOADS: [&str; 3] = ["sum", "min", "max"];
+
+// 是否按统计函数计算
+pub fn is_statistics_call(name: &str, args: &[Value]) -> bool {
+    STATISTICS_FUNCTIONS.contains(&name)
+        || (LIST_OVERLOADS.contains(&name) && matches!(args, [Value::List(_)]))
+}
+
+// 计算统计函数，调用前用 is_statistics_call 判断
+pub fn call(name: &str, args: &[Value]) -> Result<Value, CalcError> {
+    let expected = match name {
+        "percentile" | "linreg" | "corr" => 2,
+        _ => 1,
+    };
+    if args.len() != expected {
+        return Err(CalcError::ArgumentCount {
+            name: name.to_string(),
+            expected: expected.to_string(),
+            actual: args.len(),
+        });
+    }
+    let data = numbers(name, &args[0])?;
+
+    let result = match name {
+        "count" => integer(data.len()),
+        "sum" => total(&data)?,
+        "prod" => data.iter().try_fold(integer(1), |product, x| Number::apply(BinaryOp::Mul, &product, x))?,
+        "min" | "max" => {
+            let wanted = if name == "min" { Ordering::Less } else { Ordering::Greater };
+            non_empty(name, &data)?;
+            data.iter()
+                .skip(1)
+                .fold(&data[0], |best, x| if x.compare(best) == wanted { x } else { best })
+                .clone()
+        },
+        "mean" => mean(name, &data)?,
+        "median" => percentile_of(name, &data, &Number::apply(BinaryOp::Div, &integer(1), &integer(2))?)?,
+        "mode" => mode(name, &data)?,
+        "variance" => variance(name, &data)?,
+        "stdev" => Number::Float(variance(name, &data)?.to_f64().sqrt()),
+        "percentile" => {
+            let percent = args[1]
+                .as_number()
+                .filter(|p| (0.0..=100.0).contains(&p.to_f64()))
+                .ok_or_else(|| CalcError::Math(format!("百分位必须在 0 到 100 之间: {}", args[1])))?;
+            let fraction = Number::apply(BinaryOp::Div, percent, &integer(100))?;
+            percentile_of(name, &data, &fraction)?
+        },
+        "linreg" => {
+            let (slope, intercept) = linear_regression(&data, &numbers(name, &args[1])?)?;
+            return Ok(Value::List(vec![Value::Number(slope), Value::Number(intercept)]));
+        },
+        "corr" => correlation(&data, &numbers(name, &args[1])?)?,
+        _ => return Err(CalcError::UndefinedFunction(name.to_string())),
+    };
+    Ok(Value::Number(result))
+}
+
+// 取出列表中的数值，元素必须是纯数值
+fn numbers(name: &str, value: &Value) -> Result<Vec<Number>, CalcError> {
+    let Value::List(items) = value else {
+        return Err(CalcError::InvalidInput(format!("{} 的参数必须是列表，例如 {}([1, 2, 3])", name, name)));
+    };
+    items
+        .iter()
+        .map(|item| {
+            item.as_number()
+                .cloned()
+                .ok_or_else(|| CalcError::Unit(format!("统计函数 {} 只接受纯数值的列表: {}", name, item)))
+        })
+        .collect()
+}
+
+fn non_empty(name: &str, data: &[Number]) -> Result<(), CalcError> {
+    if data.is_empty() {
+        return Err(CalcError::Math(format!("{} 的列表不能为空", name)));
+    }
+    Ok(())
+}
+
+// 精确的整数，和浮点数运算时自动转换为浮点数
+fn integer(value: usize) -> Number {
+    Number::Exact(Rational::from_integer(BigInt::from_u64(value as u64)))
+}
+
+fn total(data: &[Number]) -> Result<Number, CalcError> {
+    data.iter().try_fold(integer(0), |sum, x| Number::apply(BinaryOp::Add, &sum, x))
+}
+
+fn mean(name: &str, data: &[Number]) -> Result<Number, CalcError> {
+    non_empty(name, data)?;
+    Number::apply(BinaryOp::Div, &total(data)?, &integer(data.len()))
+}
+
+// 出现次数最多的值，次数相同时取最小的
+fn mode(name: &str, data: &[Number]) -> Result<Number, CalcError> {
+    non_empty(name, data)?;
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for x in data {
+        *counts.entry(x.to_string()).or_insert(0) += 1;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.compare(b));
+    let most = counts.values().copied().max().unwrap_or(0);
+    let mode = sorted.iter().find(|x| counts[&x.to_string()] == most).expect("列表不为空");
+    Ok(mode.clone())
+}
+
+// 样本方差：偏差平方和除以 n - 1
+fn variance(name: &str, data: &[Number]) -> Result<Number, CalcError> {
+    if data.len() < 2 {
+        return Err(CalcError::Math(format!("{} 至少需要 2 个数据", name)));
+    }
+    let squares = sum_of_products(data, data)?;
+    Number::apply(BinaryOp::Div, &squares, &integer(data.len() - 1))
+}
+
+// 百分位（fraction 在 0 到 1 之间），在相邻的两个排序值之间线性插值
+fn percentile_of(name: &str, data: &[Number], fraction: &Number) -> Result<Number, CalcError> {
+    non_empty(name, data)?;
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.compare(b));
+
+    // 位置 = fraction * (n - 1)，整数部分是下标，小数部分是插值比例
+    let position = Number::apply(BinaryOp::Mul, fraction, &integer(sorted.len() - 1))?;
+    let index = (position.to_f64().floor() as usize).min(sorted.len() - 1);
+    let weight = Number::apply(BinaryOp::Sub, &position, &integer(index))?;
+    if index + 1 == sorted.len() || weight.is_zero() {
+        return Ok(sorted[index].clone());
+    }
+    let gap = Number::apply(BinaryOp::Sub, &sorted[index + 1], &sorted[index])?;
+    let offset = Number::apply(BinaryOp::Mul, &weight, &gap)?;
+    Number::apply(BinaryOp::Add, &sorted[index], &offset)
+}
+
+// 偏差乘积之和：Σ (x - x̄)(y - ȳ)
+fn sum_of_products(xs: &[Number], ys: &[Number]) -> Result<Number, CalcError> {
+    let (x_mean, y_mean) = (mean("mean", xs)?, mean("mean", ys)?);
+    xs.iter().zip(ys).try_fold(integer(0), |sum, (x, y)| {
+        let dx = Number::apply(BinaryOp::Sub, x, &x_mean)?;
+        let dy = Number::apply(BinaryOp::Sub, y, &y_mean)?;
+        Number::apply(BinaryOp::Add, &sum, &Number::apply(BinaryOp::Mul, &dx, &dy)?)
+    })
+}
+
+// 两个列表的长度必须相同，并且至少有 2 个数据
+fn paired(name: &str, xs: &[Number], ys: &[Number]) -> Result<(), CalcError> {
+    if xs.len() != ys.len() {
+        return Err(CalcError::Math(format!("{} 的两个列表长度不同: {} 和 {}", name, xs.len(), ys.len())));
+    }
+    if xs.len() < 2 {
+        return Err(CalcError::Math(format!("{} 至少需要 2 对数据", name)));
+    }
+    Ok(())
+}
+
+// 最小二乘法拟合 y = slope * x + intercept
+fn linear_regression(xs: &[Number], ys: &[Number]) -> Result<(Number, Number), CalcError> {
+    paired("linreg", xs, ys)?;
+    let sxx = sum_of_products(xs, xs)?;
+    if sxx.is_zero() {
+        return Err(CalcError::Math("linreg 的 x 值全部相同，无法拟合直线".to_string()));
+    }
+    let slope = Number::apply(BinaryOp::Div, &sum_of_products(xs, ys)?, &sxx)?;
+    let shift = Number::apply(BinaryOp::Mul, &slope, &mean("linreg", xs)?)?;
+    let intercept = Number::apply(BinaryOp::Sub, &mean("linreg", ys)?, &shift)?;
+    Ok((slope, intercept))
+}
+
+// 皮尔逊相关系数，结果在 -1 到 1 之间
+fn correlation(xs: &[Number], ys: &[Number]) -> Result<Number, CalcError> {
+    paired("corr", xs, ys)?;
+    let (sxx, syy) = (sum_of_products(xs, xs)?, sum_of_products(ys, ys)?);
+    if sxx.is_zero() || syy.is_zero() {
+        return Err(CalcError::Math("corr 的某个列表的值全部相同，相关系数没有定义".to_string()));
+    }
+    let sxy = sum_of_products(xs, ys)?.to_f64();
+    Ok(Number::Float(sxy / (sxx.to_f64() * syy.to_f64()).sqrt()))
+}
@@ -0,0 +1,331 @@
+// 物理单位 - 量纲分析、SI 前缀和单位换算
+// 学习目标：常量表、数组运算、结构体方法、Display trait
+
+use std::fmt;
+
+use crate::bigint::BigInt;
+use crate::error::CalcError;
+use crate::parser::{BinaryOp, Expr};
+use crate::rational::Rational;
+
+// 基本量纲的个数：长度、质量、时间、数据量、温度
+const BASE_DIMENSIONS: usize = 5;
+
+// 量纲：每个基本量纲的指数，例如速度是 长度^1 * 时间^-1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dimension([i32; BASE_DIMENSIONS]);
+
+impl Dimension {
+    const NONE: Dimension = Dimension([0; BASE_DIMENSIONS]);
+
+    fn add(self, other: Dimension) -> Dimension {
+        let mut result = self.0;
+        for (exponent, other) in result.iter_mut().zip(other.0) {
+            *exponent += other;
+        }
+        Dimension(result)
+    }
+
+    fn scale(self, power: i32) -> Dimension {
+        Dimension(self.0.map(|exponent| exponent * power))
+    }
+
+    pub fn is_none(self) -> bool {
+        self == Dimension::NONE
+    }
+}
+
+// 单位可以使用哪些前缀
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Prefixes {
+    None,   // 不能加前缀，例如 ft、min
+    Si,     // 全部 SI 前缀，例如 km、ms、ug
+    Data,   // 放大的 SI 前缀和二进制前缀，例如 kB、MiB
+}
+
+// 单位定义：换算到 SI 基本单位时，SI 值 = (数值 + offset) * factor
+// factor 和 offset 用字符串书写，解析成精确有理数，保证 1 mi = 1609.344 m 没有误差
+struct UnitDef {
+    symbol: &'static str,
+    factor: &'static str,
+    offset: &'static str,
+    dimension: [i32; BASE_DIMENSIONS],
+    prefixes: Prefixes,
+}
+
+const LENGTH: [i32; BASE_DIMENSIONS] = [1, 0, 0, 0, 0];
+const VOLUME: [i32; BASE_DIMENSIONS] = [3, 0, 0, 0, 0];
+const MASS: [i32; BASE_DIMENSIONS] = [0, 1, 0, 0, 0];
+const TIME: [i32; BASE_DIMENSIONS] = [0, 0, 1, 0, 0];
+const SPEED: [i32; BASE_DIMENSIONS] = [1, 0, -1, 0, 0];
+const DATA: [i32; BASE_DIMENSIONS] = [0, 0, 0, 1, 0];
+const TEMPERATURE: [i32; BASE_DIMENSIONS] = [0, 0, 0, 0, 1];
+
+// 支持的单位（基本单位：米、千克、秒、比特、开尔文）
+const UNITS: &[UnitDef] = &[
+    // 长度
+    UnitDef { symbol: "m", factor: "1", offset: "0", dimension: LENGTH, prefixes: Prefixes::Si },
+    UnitDef { symbol: "in", factor: "0.0254", offset: "0", dimension: LENGTH, prefixes: Prefixes::None },
+    UnitDef { symbol: "ft", factor: "0.3048", offset: "0", dimension: LENGTH, prefixes: Prefixes::None },
+    UnitDef { symbol: "yd", factor: "0.9144", offset: "0", dimension: LENGTH, prefixes: Prefixes::None },
+    UnitDef { symbol: "mi", factor: "1609.344", offset: "0", dimension: LENGTH, prefixes: Prefixes::None },
+    UnitDef { symbol: "nmi", factor: "1852", offset: "0", dimension: LENGTH, prefixes: Prefixes::None },
+    // 体积
+    UnitDef { symbol: "L", factor: "0.001", offset: "0", dimension: VOLUME, prefixes: Prefixes::Si },
+    // 质量
+    UnitDef { symbol: "g", factor: "0.001", offset: "0", dimension: MASS, prefixes: Prefixes::Si },
+    UnitDef { symbol: "t", factor: "1000", offset: "0", dimension: MASS, prefixes: Prefixes::None },
+    UnitDef { symbol: "lb", factor: "0.45359237", offset: "0", dimension: MASS, prefixes: Prefixes::None },
+    UnitDef { symbol: "oz", factor: "0.028349523125", offset: "0", dimension: MASS, prefixes: Prefixes::None },
+    // 时间
+    UnitDef { symbol: "s", factor: "1", offset: "0", dimension: TIME, prefixes: Prefixes::Si },
+    UnitDef { symbol: "min", factor: "60", offset: "0", dimension: TIME, prefixes: Prefixes::None },
+    UnitDef { symbol: "h", factor: "3600", offset: "0", dimension: TIME, prefixes: Prefixes::None },
+    UnitDef { symbol: "day", factor: "86400", offset: "0", dimension: TIME, prefixes: Prefixes::None },
+    UnitDef { symbol: "week", factor: "604800", offset: "0", dimension: TIME, prefixes: Prefixes::None },
+    // 速度
+    UnitDef { symbol: "mph", factor: "0.44704", offset: "0", dimension: SPEED, prefixes: Prefixes::None },
+    UnitDef { symbol: "kn", factor: "463/900", offset: "0", dimension: SPEED, prefixes: Prefixes::None },
+    // 数据量
+    UnitDef { symbol: "bit", factor: "1", offset: "0", dimension: DATA, prefixes: Prefixes::Data },
+    UnitDef { symbol: "B", factor: "8", offset: "0", dimension: DATA, prefixes: Prefixes::Data },
+    // 温度：degC 和 degF 是带偏移的单位
+    UnitDef { symbol: "K", factor: "1", offset: "0", dimension: TEMPERATURE, prefixes: Prefixes::None },
+    UnitDef { symbol: "degC", factor: "1", offset: "273.15", dimension: TEMPERATURE, prefixes: Prefixes::None },
+    UnitDef { symbol: "degF", factor: "5/9", offset: "459.67", dimension: TEMPERATURE, prefixes: Prefixes::None },
+];
+
+// SI 前缀
+const SI_PREFIXES: &[(&str, &str)] = &[
+    ("p", "1e-12"),
+    ("n", "1e-9"),
+    ("u", "1e-6"),
+    ("µ", "1e-6"),
+    ("m", "1e-3"),
+    ("c", "1e-2"),
+    ("k", "1e3"),
+    ("M", "1e6"),
+    ("G", "1e9"),
+    ("T", "1e12"),
+    ("P", "1e15"),
+];
+
+// 数据量使用的前缀：放大的 SI 前缀（kB = 1000 B）和二进制前缀（KiB = 1024 B）
+const DATA_PREFIXES: &[(&str, &str)] = &[
+    ("k", "1e3"),
+    ("M", "1e6"),
+    ("G", "1e9"),
+    ("T", "1e12"),
+    ("P", "1e15"),
+    ("Ki", "1024"),
+    ("Mi", "1048576"),
+    ("Gi", "1073741824"),
+    ("Ti", "1099511627776"),
+    ("Pi", "1125899906842624"),
+];
+
+// 解析单位表中的系数，支持 "0.3048" 和 "5/9" 两种写法
+fn parse_factor(text: &str) -> Rational {
+    match text.split_once('/') {
+        Some((numer, denom)) => {
+            let numer = BigInt::parse(numer).expect("单位表中的系数有效");
+            let denom = BigInt::parse(denom).expect("单位表中的系数有效");
+            Rational::new(numer, denom).expect("单位表中的系数有效")
+        },
+        None => Rational::parse_decimal(text).expect("单位表中的系数有效"),
+    }
+}
+
+// 查找单个单位符号（可以带前缀），例如 "km" -> 1000 * m
+struct Resolved {
+    factor: Rational,
+    offset: Rational,
+    dimension: Dimension,
+}
+
+fn resolve(symbol: &str) -> Option<Resolved> {
+    let found = |def: &UnitDef, prefix: Rational| Resolved {
+        factor: prefix.mul(&parse_factor(def.factor)),
+        offset: parse_factor(def.offset),
+        dimension: Dimension(def.dimension),
+    };
+
+    // 完整的符号优先，这样 min 是分钟而不是 milli-in
+    if let Some(def) = UNITS.iter().find(|def| def.symbol == symbol) {
+        return Some(found(def, Rational::from_integer(BigInt::one())));
+    }
+    for def in UNITS {
+        let prefixes = match def.prefixes {
+            Prefixes::None => continue,
+            Prefixes::Si => SI_PREFIXES,
+            Prefixes::Data => DATA_PREFIXES,
+        };
+        let Some(prefix) = symbol.strip_suffix(def.symbol) else {
+            continue;
+        };
+        if let Some((_, factor)) = prefixes.iter().find(|(name, _)| *name == prefix) {
+            return Some(found(def, parse_factor(factor)));
+        }
+    }
+    None
+}
+
+// 复合单位，例如 km、m/s、kg*m/s^2；每一项是单位符号和指数
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+    terms: Vec<(String, i32)>,
+}
+
+impl Unit {
+    // 没有单位（纯数）
+    pub fn none() -> Unit {
+        Unit { terms: Vec::new() }
+    }
+
+    // 单个单位符号，未知的符号返回 None
+    pub fn parse_symbol(symbol: &str) -> Option<Unit> {
+        resolve(symbol)?;
+        Some(Unit {
+            terms: vec![(symbol.to_string(), 1)],
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.terms.iter().fold(Dimension::NONE, |dimension, (symbol, power)| {
+            dimension.add(resolve(symbol).expect("单位已检查").dimension.scale(*power))
+        })
+    }
+
+    // 换算到 SI 基本单位的系数
+    pub fn factor(&self) -> Rational {
+        self.terms.iter().fold(Rational::from_integer(BigInt::one()), |factor, (symbol, power)| {
+            let term = resolve(symbol).expect("单位已检查").factor;
+            factor.mul(&term.pow(*power as i64).expect("单位系数不为零"))
+        })
+    }
+
+    // 温度偏移：只有单独的 degC、degF 有偏移，其他单位都是 0
+    pub fn offset(&self) -> Rational {
+        match &self.terms[..] {
+            [(symbol, 1)] => resolve(symbol).expect("单位已检查").offset,
+            _ => Rational::from_integer(BigInt::zero()),
+        }
+    }
+
+    // 含有带偏移的单位（degC、degF）时，不能参与乘除和乘方
+    pub fn is_affine(&self) -> bool {
+        self.terms
+            .iter()
+            .any(|(symbol, _)| !resolve(symbol).expect("单位已检查").offset.is_zero())
+    }
+
+    // 单位相乘，只合并完全相同的符号（m * m = m^2）
+    pub fn mul(&self, other: &Unit) -> Unit {
+        let mut terms = self.terms.clone();
+        for (symbol, power) in &other.terms {
+            match terms.iter_mut().find(|(existing, _)| existing == symbol) {
+                Some((_, existing_power)) => *existing_power += power,
+                None => terms.push((symbol.clone(), *power)),
+            }
+        }
+        terms.retain(|(_, power)| *power != 0);
+        Unit { terms }
+    }
+
+    pub fn powi(&self, power: i32) -> Unit {
+        let mut terms: Vec<(String, i32)> = self
+            .terms
+            .iter()
+            .map(|(symbol, existing)| (symbol.clone(), existing * power))
+            .collect();
+        terms.retain(|(_, power)| *power != 0);
+        Unit { terms }
+    }
+
+    // 把量纲相同的项换算成先出现的那一项，例如 km * m -> km^2（系数 1/1000）
+    // 返回化简后的单位和数值需要乘上的系数
+    pub fn simplify(&self) -> (Unit, Rational) {
+        let mut scale = Rational::from_integer(BigInt::one());
+        let mut terms: Vec<(String, i32)> = Vec::new();
+        for (symbol, power) in &self.terms {
+            let term = resolve(symbol).expect("单位已检查");
+            let same_dimension = terms.iter_mut().find(|(existing, _)| {
+                let existing = resolve(existing).expect("单位已检查");
+                existing.dimension == term.dimension && existing.offset.is_zero() && term.offset.is_zero()
+            });
+            match same_dimension {
+                Some((existing, existing_power)) => {
+                    let ratio = term
+                        .factor
+                        .div(&resolve(existing).expect("单位已检查").factor)
+                        .expect("单位系数不为零");
+                    scale = scale.mul(&ratio.pow(*power as i64).expect("单位系数不为零"));
+                    *existing_power += power;
+                },
+                None => terms.push((symbol.clone(), *power)),
+            }
+        }
+        terms.retain(|(_, power)| *power != 0);
+        (Unit { terms }, scale)
+    }
+}
+
+impl fmt::Display for Unit {
+    // 正指数的项用 * 连接，负指数的项放在 / 后面：kg*m/s^2、1/s、m/(s*kg)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format_term = |symbol: &str, power: i32| {
+            if power == 1 {
+                symbol.to_string()
+            } else {
+                format!("{}^{}", symbol, power)
+            }
+        };
+        let numerator: Vec<String> = self
+            .terms
+            .iter()
+            .filter(|(_, power)| *power > 0)
+            .map(|(symbol, power)| format_term(symbol, *power))
+            .collect();
+        let denominator: Vec<String> = self
+            .terms
+            .iter()
+            .filter(|(_, power)| *power < 0)
+            .map(|(symbol, power)| format_term(symbol, -power))
+            .collect();
+
+        let mut text = if numerator.is_empty() { "1".to_string() } else { numerator.join("*") };
+        match denominator.len() {
+            0 => {},
+            1 => text = format!("{}/{}", text, denominator[0]),
+            _ => text = format!("{}/({})", text, denominator.join("*")),
+        }
+        f.pad(&text)
+    }
+}
+
+// 把 to 右边的表达式解释成单位，例如 km、m/s、m^2、1/s
+pub fn unit_from_expr(expr: &Expr) -> Result<Unit, CalcError> {
+    let invalid = || CalcError::Unit("to 后面必须是单位，例如 km、m/s、degF".to_string());
+    match expr {
+        Expr::Variable(name) => Unit::parse_symbol(name).ok_or_else(|| CalcError::Unit(format!("未知的单位: {}", name))),
+        Expr::Number(text) if text == "1" => Ok(Unit::none()),
+        Expr::Binary(BinaryOp::Mul, lhs, rhs) => Ok(unit_from_expr(lhs)?.mul(&unit_from_expr(rhs)?)),
+        Expr::Binary(BinaryOp::Div, lhs, rhs) => Ok(unit_from_expr(lhs)?.mul(&unit_from_expr(rhs)?.powi(-1))),
+        Expr::Binary(BinaryOp::Pow, base, exponent) => {
+            let power = match exponent.as_ref() {
+                Expr::Number(text) => text.parse::<i32>().map_err(|_| invalid())?,
+                Expr::Neg(inner) => match inner.as_ref() {
+                    Expr::Number(text) => -text.parse::<i32>().map_err(|_| invalid())?,
+                    _ => return Err(invalid()),
+                },
+                _ => return Err(invalid()),
+            };
+            Ok(unit_from_expr(base)?.powi(power))
+        },
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,324 @@
+// 计算器中的值 - 纯数值、带单位的物理量、复数或列表
+// 学习目标：枚举组合已有类型、量纲检查、错误处理
+
+use std::fmt;
+
+use crate::complex::{self, Complex};
+use crate::error::CalcError;
+use crate::matrix;
+use crate::number::{Number, NumberMode};
+use crate::parser::BinaryOp;
+use crate::units::Unit;
+
+// 物理量：数值 + 单位，数值按这个单位计数（5.3 km 存成 5.3 和 km）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    pub magnitude: Number,
+    pub unit: Unit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(Number),
+    Quantity(Quantity),
+    Complex(Complex), // 3 + 4i，虚部不为零
+    List(Vec<Value>), // [1, 4, 9, 16]，算术运算按元素进行
+}
+
+impl From<Number> for Value {
+    fn from(number: Number) -> Self {
+        Value::Number(number)
+    }
+}
+
+impl Value {
+    pub fn zero() -> Value {
+        Value::Number(Number::zero())
+    }
+
+    // 创建复数；虚部为零时化为实数
+    pub fn complex(z: Complex) -> Value {
+        if z.im == 0.0 {
+            return Value::Number(Number::Float(z.re));
+        }
+        Value::Complex(z)
+    }
+
+    // 创建物理量；没有量纲的结果（例如 km/m）化为纯数值
+    pub fn quantity(magnitude: Number, unit: Unit) -> Value {
+        if unit.is_empty() {
+            return Value::Number(magnitude);
+        }
+        if unit.dimension().is_none() {
+            let factor = Number::Exact(unit.factor());
+            return Value::Number(Number::apply(BinaryOp::Mul, &magnitude, &factor).expect("乘法不会出错"));
+        }
+        Value::Quantity(Quantity { magnitude, unit })
+    }
+
+    // 数值部分，复数和列表没有单独的数值部分，返回 None
+    pub fn magnitude(&self) -> Option<&Number> {
+        match self {
+            Value::Number(number) => Some(number),
+            Value::Quantity(quantity) => Some(&quantity.magnitude),
+            Value::Complex(_) | Value::List(_) => None,
+        }
+    }
+
+    // 数值和物理量之间运算时的数值部分，列表已经按元素展开
+    fn scalar_magnitude(&self) -> &Number {
+        self.magnitude().expect("列表已经按元素展开")
+    }
+
+    // 单位部分，纯数值没有单位；只用于数值和物理量之间的运算
+    fn unit(&self) -> Unit {
+        match self {
+            Value::Quantity(quantity) => quantity.unit.clone(),
+            Value::Number(_) | Value::Complex(_) | Value::List(_) => Unit::none(),
+        }
+    }
+
+    // 纯数值时返回 Some，函数参数等只接受纯数值的地方使用
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Value::Number(number) => Some(number),
+            Value::Quantity(_) | Value::Complex(_) | Value::List(_) => None,
+        }
+    }
+
+    // 列表的每个元素都是零时也算零
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Value::List(items) => items.iter().all(Value::is_zero),
+            Value::Complex(z) => z.re == 0.0 && z.im == 0.0,
+            _ => self.magnitude().is_some_and(Number::is_zero),
+        }
+    }
+
+    pub fn neg(&self) -> Value {
+        self.map_magnitude(&|magnitude| Ok(magnitude.neg())).expect("取负不会出错")
+    }
+
+    // 按位取反，只能用于纯数值
+    pub fn bit_not(&self) -> Result<Value, CalcError> {
+        match self {
+            Value::Number(number) => number.bit_not().map(Value::Number),
+            Value::Quantity(_) => Err(CalcError::Unit(format!("位运算不能用于带单位的数值: {}", self))),
+            Value::Complex(_) => Err(CalcError::Math(format!("位运算不能用于复数: {}", self))),
+            Value::List(items) => items.iter().map(Value::bit_not).collect::<Result<_, _>>().map(Value::List),
+        }
+    }
+
+    // 转换计算模式，单位不变
+    pub fn convert(&self, mode: NumberMode) -> Value {
+        self.map_magnitude(&|magnitude| Ok(magnitude.convert(mode))).expect("转换不会出错")
+    }
+
+    // 对数值部分（列表中每个元素的数值部分、复数的实部和虚部）应用 f，单位不变
+    pub fn map_magnitude(&self, f: &impl Fn(&Number) -> Result<Number, CalcError>) -> Result<Value, CalcError> {
+        let mapped = match self {
+            Value::Number(number) => Value::Number(f(number)?),
+            Value::Quantity(quantity) => Value::Quantity(Quantity {
+                magnitude: f(&quantity.magnitude)?,
+                unit: quantity.unit.clone(),
+            }),
+            Value::Complex(z) => Value::complex(Complex::new(
+                f(&Number::Float(z.re))?.to_f64(),
+                f(&Number::Float(z.im))?.to_f64(),
+            )),
+            Value::List(items) => Value::List(items.iter().map(|item| item.map_magnitude(f)).collect::<Result<_, _>>()?),
+        };
+        Ok(mapped)
+    }
+
+    // 按元素计算：参数中的列表长度必须相同，其他参数在每个位置重复使用
+    // [1, 2] + 10 = [11, 12]，[1, 2] * [3, 4] = [3, 8]
+    pub fn broadcast(
+        args: &[Value],
+        f: &mut impl FnMut(&[Value]) -> Result<Value, CalcError>,
+    ) -> Result<Value, CalcError> {
+        let mut len = None;
+        for arg in args {
+            if let Value::List(items) = arg {
+                match len {
+                    Some(len) if len != items.len() => {
+                        return Err(CalcError::InvalidInput(format!("列表长度不同: {} 和 {}", len, items.len())));
+                    },
+                    _ => len = Some(items.len()),
+                }
+            }
+        }
+        let Some(len) = len else {
+            return f(args);
+        };
+
+        let mut results = Vec::with_capacity(len);
+        for index in 0..len {
+            let row: Vec<Value> = args
+                .iter()
+                .map(|arg| match arg {
+                    Value::List(items) => items[index].clone(),
+                    scalar => scalar.clone(),
+                })
+                .collect();
+            results.push(Value::broadcast(&row, f)?);
+        }
+        Ok(Value::List(results))
+    }
+
+    // 执行二元运算，带单位时先做量纲检查，有列表时按元素计算
+    // 例外是矩阵：矩阵和矩阵或向量相乘是矩阵乘法，矩阵的整数次幂是连乘
+    pub fn apply(op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+        if let (Value::List(_), Value::List(_)) = (lhs, rhs) {
+            if op == BinaryOp::Mul && (matrix::is_matrix(lhs) || matrix::is_matrix(rhs)) {
+                return matrix::product(lhs, rhs);
+            }
+            if matrix::shape(lhs) != matrix::shape(rhs) {
+                return Err(matrix::shape_mismatch(verb(op), lhs, rhs));
+            }
+        }
+        if let (true, Value::Number(exponent)) = (op == BinaryOp::Pow && matrix::is_matrix(lhs), rhs) {
+            return matrix::power(lhs, exponent);
+        }
+        if matches!(lhs, Value::List(_)) || matches!(rhs, Value::List(_)) {
+            return Value::broadcast(&[lhs.clone(), rhs.clone()], &mut |pair| Value::apply(op, &pair[0], &pair[1]));
+        }
+        if matches!(lhs, Value::Complex(_)) || matches!(rhs, Value::Complex(_)) {
+            return complex::apply(op, lhs, rhs);
+        }
+        if let (Value::Number(a), Value::Number(b)) = (lhs, rhs) {
+            return Number::apply(op, a, b).map(Value::Number);
+        }
+        match op {
+            BinaryOp::Add | BinaryOp::Sub => Value::add(op, lhs, rhs),
+            BinaryOp::Mul | BinaryOp::Div => Value::multiply(op, lhs, rhs),
+            BinaryOp::Pow => Value::power(lhs, rhs),
+            _ => Err(CalcError::Unit(format!("位运算不能用于带单位的数值: {} 和 {}", lhs, rhs))),
+        }
+    }
+
+    // 加减：量纲必须相同，右边换算成左边的单位
+    fn add(op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+        let verb = verb(op);
+        let (lhs_unit, rhs_unit) = (lhs.unit(), rhs.unit());
+        if lhs_unit.is_empty() || rhs_unit.is_empty() {
+            let unit = if lhs_unit.is_empty() { rhs_unit } else { lhs_unit };
+            return Err(CalcError::Unit(format!("不能把没有单位的数值和 {} {}", unit, verb)));
+        }
+        if lhs_unit.dimension() != rhs_unit.dimension() {
+            return Err(CalcError::DimensionMismatch {
+                operation: verb,
+                lhs: lhs_unit.to_string(),
+                rhs: rhs_unit.to_string(),
+            });
+        }
+
+        // 温度相加时右边按温差处理：20 degC + 10 K = 30 degC
+        let ratio = rhs_unit.factor().div(&lhs_unit.factor()).expect("单位系数不为零");
+        let rhs = Number::apply(BinaryOp::Mul, rhs.scalar_magnitude(), &Number::Exact(ratio))?;
+        let magnitude = Number::apply(op, lhs.scalar_magnitude(), &rhs)?;
+        Ok(Value::quantity(magnitude, lhs_unit))
+    }
+
+    // 乘除：数值和单位分别相乘，再把量纲相同的单位合并
+    fn multiply(op: BinaryOp, lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+        let (lhs_unit, rhs_unit) = (lhs.unit(), rhs.unit());
+        // 温度乘除纯数还有意义（2 * 10 degC），和其他单位相乘就没有意义了
+        let scaling = match op {
+            BinaryOp::Mul => lhs_unit.is_empty() || rhs_unit.is_empty(),
+            _ => rhs_unit.is_empty(),
+        };
+        let affine = [&lhs_unit, &rhs_unit].into_iter().find(|unit| unit.is_affine());
+        let affine = if scaling { None } else { affine };
+        if let Some(unit) = affine {
+            return Err(CalcError::Unit(format!("{} 是带偏移的温度单位，不能参与乘除运算，请先转换为 K", unit)));
+        }
+
+        let magnitude = Number::apply(op, lhs.scalar_magnitude(), rhs.scalar_magnitude())?;
+        let rhs_unit = if op == BinaryOp::Div { rhs_unit.powi(-1) } else { rhs_unit };
+        let (unit, scale) = lhs_unit.mul(&rhs_unit).simplify();
+        let magnitude = Number::apply(BinaryOp::Mul, &magnitude, &Number::Exact(scale))?;
+        Ok(Value::quantity(magnitude, unit))
+    }
+
+    // 乘方：带单位的数值只能做整数次幂，指数不能带单位
+    fn power(lhs: &Value, rhs: &Value) -> Result<Value, CalcError> {
+        let Value::Number(exponent) = rhs else {
+            return Err(CalcError::Unit(format!("指数不能带单位: {}", rhs)));
+        };
+        let Value::Quantity(base) = lhs else {
+            unreachable!("两个纯数值的情况已经处理");
+        };
+        let power = integer_exponent(exponent)
+            .ok_or_else(|| CalcError::Unit(format!("带单位的数值只能做整数次幂: ({})^{}", lhs, exponent)))?;
+        if base.unit.is_affine() && power != 1 {
+            return Err(CalcError::Unit(format!("{} 是带偏移的温度单位，不能做乘方运算，请先转换为 K", base.unit)));
+        }
+        let magnitude = Number::apply(BinaryOp::Pow, &base.magnitude, exponent)?;
+        Ok(Value::quantity(magnitude, base.unit.powi(power)))
+    }
+
+    // 单位换算（to 运算符）：60 mph to m/s
+    pub fn convert_to(&self, target: &Unit) -> Result<Value, CalcError> {
+        if let Value::List(items) = self {
+            return items.iter().map(|item| item.convert_to(target)).collect::<Result<_, _>>().map(Value::List);
+        }
+        let Value::Quantity(quantity) = self else {
+            return Err(CalcError::Unit(format!("没有单位的数值 {} 不能转换为 {}", self, target)));
+        };
+        if quantity.unit.dimension() != target.dimension() {
+            return Err(CalcError::Unit(format!("不能把 {} 转换为 {}，量纲不同", quantity.unit, target)));
+        }
+
+        // 新数值 = 数值 * ratio + shift，shift 只在温度换算时不为零（degC -> degF 是 *9/5 + 32）
+        let ratio = quantity.unit.factor().div(&target.factor()).expect("单位系数不为零");
+        let shift = quantity.unit.offset().mul(&ratio).sub(&target.offset());
+        let mut magnitude = Number::apply(BinaryOp::Mul, &quantity.magnitude, &Number::Exact(ratio))?;
+        if !shift.is_zero() {
+            magnitude = Number::apply(BinaryOp::Add, &magnitude, &Number::Exact(shift))?;
+        }
+        Ok(Value::quantity(magnitude, target.clone()))
+    }
+}
+
+// 错误信息中的运算名称：不能把 m 和 s 相加
+fn verb(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "相加",
+        BinaryOp::Sub => "相减",
+        BinaryOp::Mul => "相乘",
+        BinaryOp::Div => "相除",
+        BinaryOp::Pow => "做乘方运算",
+        _ => "做位运算",
+    }
+}
+
+// 把指数转换成整数，非整数或过大时返回 None
+fn integer_exponent(exponent: &Number) -> Option<i32> {
+    match exponent {
+        Number::Exact(value) if value.is_integer() => i32::try_from(value.numer().to_i64()?).ok(),
+        Number::Exact(_) => None,
+        Number::Float(value) if value.fract() == 0.0 && value.abs() <= i32::MAX as f64 => Some(*value as i32),
+        Number::Float(_) => None,
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Quantity(quantity) => write!(f, "{} {}", quantity.magnitude, quantity.unit),
+            Value::Complex(z) => write!(f, "{}", z),
+            Value::List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            },
+        }
+    }
+}
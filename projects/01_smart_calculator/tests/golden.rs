@@ -0,0 +1,117 @@
+// 黄金文件测试 - 执行 tests/golden/*.calc 中的脚本，把输出和 *.out 中记录的文本逐行比较
+// 学习目标：集成测试、读取测试数据文件、用环境变量更新期望输出
+//
+// 每个 .calc 文件从全新的计算器开始执行，每条输入在输出中写成 "> 输入"，下面是它的结果；
+// 空行和只有注释的行原样保留，方便对照阅读。
+// 修改了输出后用 UPDATE_GOLDEN=1 cargo test 重新生成 .out 文件，再用 git diff 检查变化是否符合预期。
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use smart_calculator::{execute, Calculator, Reply};
+
+// 设置这个环境变量时把实际输出写回 .out 文件，而不是比较
+const UPDATE_ENV_VAR: &str = "UPDATE_GOLDEN";
+
+// 执行脚本，生成输出文本
+fn transcript(script: &str) -> String {
+    let mut calculator = Calculator::new();
+    let mut lines = Vec::new();
+
+    for line in script.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            lines.push(line.to_string());
+            continue;
+        }
+        lines.push(format!("> {}", line));
+        match execute(&mut calculator, line) {
+            Ok(Reply::Result(value)) => lines.push(calculator.describe_value(&value)),
+            Ok(Reply::Report(value, report)) => {
+                lines.push(calculator.describe_value(&value));
+                lines.push(format!("  {}", report));
+            },
+            Ok(Reply::Expression(expr)) => lines.push(expr.to_string()),
+            Ok(Reply::Message(message)) => lines.push(message),
+            Ok(Reply::Listing(listing)) => lines.extend(listing),
+            Ok(Reply::Nothing) => {},
+            Ok(Reply::Quit) => {
+                lines.push("(退出)".to_string());
+                break;
+            },
+            Err(error) => lines.push(format!("错误: {}", error)),
+        }
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+// 所有 .calc 脚本，按文件名排序
+fn scripts() -> Vec<PathBuf> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden");
+    let mut paths: Vec<PathBuf> = fs::read_dir(&directory)
+        .unwrap_or_else(|error| panic!("无法读取 {}: {}", directory.display(), error))
+        .map(|entry| entry.expect("目录项").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "calc"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// 第一处不同的行，用于失败信息
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for number in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (expected, actual) if expected == actual => continue,
+            (expected, actual) => {
+                return format!(
+                    "第 {} 行\n  期望: {}\n  实际: {}",
+                    number,
+                    expected.unwrap_or("(文件结束)"),
+                    actual.unwrap_or("(文件结束)")
+                );
+            },
+        }
+    }
+    "只有行尾不同".to_string()
+}
+
+#[test]
+fn golden_transcripts() {
+    let update = env::var_os(UPDATE_ENV_VAR).is_some();
+    let scripts = scripts();
+    assert!(!scripts.is_empty(), "tests/golden 中没有 .calc 脚本");
+
+    let mut failures = Vec::new();
+    for script_path in scripts {
+        let script = fs::read_to_string(&script_path).expect("读取脚本");
+        let actual = transcript(&script);
+        let expected_path = script_path.with_extension("out");
+
+        if update {
+            fs::write(&expected_path, &actual).expect("写入期望输出");
+            continue;
+        }
+        match fs::read_to_string(&expected_path) {
+            Ok(expected) if expected == actual => {},
+            Ok(expected) => failures.push(format!(
+                "{}: {}",
+                script_path.display(),
+                first_difference(&expected, &actual)
+            )),
+            Err(_) => failures.push(format!(
+                "{}: 缺少期望输出 {}，可以用 {}=1 cargo test 生成",
+                script_path.display(),
+                expected_path.display(),
+                UPDATE_ENV_VAR
+            )),
+        }
+    }
+
+    assert!(failures.is_empty(), "输出与黄金文件不一致:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,286 @@
+// 性质测试 - 用随机生成的输入检查代数恒等式、语法树往返和会话文件往返
+// 学习目标：不依赖外部 crate 的伪随机数、固定种子保证可重复、失败时报告具体输入
+//
+// 每个测试从固定的种子开始生成 CASES 组输入，失败信息中包含出错的表达式，可以直接在计算器中复现。
+
+use smart_calculator::parser::{self, BinaryOp, Expr};
+use smart_calculator::session::Session;
+use smart_calculator::{execute, Calculator, Reply, Value};
+
+// 每个性质检查的随机输入组数
+const CASES: usize = 500;
+
+// xorshift64* 伪随机数生成器
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng { state: seed.max(1) }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    // [low, high) 中的整数
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low) as u64) as i64
+    }
+
+    // [low, high) 中的浮点数
+    fn float(&mut self, low: f64, high: f64) -> f64 {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        low + unit * (high - low)
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as i64) as usize]
+    }
+}
+
+// 计算表达式，结果必须是纯数值
+fn evaluate(calculator: &mut Calculator, input: &str) -> f64 {
+    match calculator.evaluate_input(input) {
+        Ok(value) => value
+            .as_number()
+            .unwrap_or_else(|| panic!("{} 的结果不是数值: {}", input, value))
+            .to_f64(),
+        Err(error) => panic!("{} 计算出错: {}", input, error),
+    }
+}
+
+// 相对误差不超过 1e-9（接近零时按绝对误差）
+fn assert_close(actual: f64, expected: f64, input: &str) {
+    let scale = expected.abs().max(1.0);
+    assert!(
+        (actual - expected).abs() <= 1e-9 * scale,
+        "{} = {}，期望约等于 {}",
+        input,
+        actual,
+        expected
+    );
+}
+
+fn exact_calculator() -> Calculator {
+    let mut calculator = Calculator::new();
+    execute(&mut calculator, "mode exact").expect("切换到精确模式");
+    calculator
+}
+
+// 随机分数，例如 (-17/6)
+fn random_fraction(rng: &mut Rng) -> String {
+    format!("({}/{})", rng.range(-1000, 1000), rng.range(1, 100))
+}
+
+#[test]
+fn float_addition_and_subtraction_are_inverse() {
+    let mut rng = Rng::new(1);
+    let mut calculator = Calculator::new();
+    for _ in 0..CASES {
+        let (a, b) = (rng.float(-1e6, 1e6), rng.float(-1e6, 1e6));
+        let input = format!("({} + {}) - {}", a, b, b);
+        let actual = evaluate(&mut calculator, &input);
+        let scale = a.abs().max(b.abs()).max(1.0);
+        assert!((actual - a).abs() <= 1e-9 * scale, "{} = {}，期望约等于 {}", input, actual, a);
+    }
+}
+
+#[test]
+fn float_multiplication_and_division_are_inverse() {
+    let mut rng = Rng::new(2);
+    let mut calculator = Calculator::new();
+    for _ in 0..CASES {
+        let a = rng.float(-1e6, 1e6);
+        let b = rng.float(0.001, 1e3) * if rng.range(0, 2) == 0 { 1.0 } else { -1.0 };
+        let input = format!("({} * {}) / {}", a, b, b);
+        assert_close(evaluate(&mut calculator, &input), a, &input);
+    }
+}
+
+#[test]
+fn elementary_functions_invert_each_other() {
+    let mut rng = Rng::new(3);
+    let mut calculator = Calculator::new();
+    for _ in 0..CASES {
+        let a = rng.float(0.001, 100.0);
+        let input = format!("exp(ln({}))", a);
+        assert_close(evaluate(&mut calculator, &input), a, &input);
+
+        let b = rng.float(-1e3, 1e3);
+        let input = format!("sqrt(({})^2)", b);
+        assert_close(evaluate(&mut calculator, &input), b.abs(), &input);
+    }
+}
+
+#[test]
+fn exact_arithmetic_identities_hold_exactly() {
+    let mut rng = Rng::new(4);
+    let mut calculator = exact_calculator();
+    for _ in 0..CASES {
+        let (a, b, c) = (random_fraction(&mut rng), random_fraction(&mut rng), random_fraction(&mut rng));
+        let identities = [
+            (format!("({} + {}) - {}", a, b, b), a.clone()),
+            (format!("{} * ({} + {})", a, b, c), format!("{} * {} + {} * {}", a, b, a, c)),
+            (format!("({} + {}) + {}", a, b, c), format!("{} + ({} + {})", a, b, c)),
+            (format!("{} * {}", a, b), format!("{} * {}", b, a)),
+        ];
+        for (lhs, rhs) in identities {
+            let left = calculator.evaluate_input(&lhs).expect("计算左边");
+            let right = calculator.evaluate_input(&rhs).expect("计算右边");
+            assert_eq!(left, right, "{} 和 {} 应该精确相等", lhs, rhs);
+        }
+
+        // 除数不为零时乘除互逆
+        if !b.starts_with("(0/") {
+            let input = format!("({} * {}) / {}", a, b, b);
+            let expected = calculator.evaluate_input(&a).expect("计算 a");
+            assert_eq!(calculator.evaluate_input(&input).expect("计算乘除"), expected, "{}", input);
+        }
+    }
+}
+
+// 随机语法树：数字、变量、一元运算、二元运算、函数调用和列表
+fn random_expr(rng: &mut Rng, depth: u32) -> Expr {
+    const OPERATORS: [BinaryOp; 10] = [
+        BinaryOp::Add,
+        BinaryOp::Sub,
+        BinaryOp::Mul,
+        BinaryOp::Div,
+        BinaryOp::Pow,
+        BinaryOp::BitAnd,
+        BinaryOp::BitOr,
+        BinaryOp::BitXor,
+        BinaryOp::Shl,
+        BinaryOp::Shr,
+    ];
+    const NUMBERS: [&str; 5] = ["0", "1", "2.5", "42", "0.125"];
+    const VARIABLES: [&str; 3] = ["x", "y", "rate"];
+    const FUNCTIONS: [&str; 3] = ["sqrt", "max", "f"];
+
+    let kind = if depth == 0 { rng.range(0, 2) } else { rng.range(0, 8) };
+    let child = |rng: &mut Rng| Box::new(random_expr(rng, depth - 1));
+    match kind {
+        0 => Expr::Number(rng.pick(&NUMBERS).to_string()),
+        1 => Expr::Variable(rng.pick(&VARIABLES).to_string()),
+        2 => Expr::Neg(child(rng)),
+        3 => Expr::BitNot(child(rng)),
+        4 => {
+            let name = rng.pick(&FUNCTIONS).to_string();
+            let args = (0..rng.range(1, 4)).map(|_| random_expr(rng, depth - 1)).collect();
+            Expr::Call(name, args)
+        },
+        5 => Expr::List((0..rng.range(0, 3)).map(|_| random_expr(rng, depth - 1)).collect()),
+        _ => Expr::Binary(*rng.pick(&OPERATORS), child(rng), child(rng)),
+    }
+}
+
+#[test]
+fn printed_expressions_parse_back_to_the_same_tree() {
+    let mut rng = Rng::new(5);
+    for _ in 0..CASES {
+        let expr = random_expr(&mut rng, 4);
+        let printed = expr.to_string();
+        match parser::parse(&printed) {
+            Ok(parsed) => assert_eq!(parsed, expr, "{} 重新解析后的语法树不同", printed),
+            Err(error) => panic!("{} 无法重新解析: {}", printed, error),
+        }
+    }
+}
+
+#[test]
+fn deeply_nested_input_is_rejected_instead_of_overflowing_the_stack() {
+    let deep = [
+        format!("{}1{}", "(".repeat(3000), ")".repeat(3000)),
+        format!("{}1", "-".repeat(3000)),
+        format!("{}1", "~".repeat(3000)),
+        format!("1{}", "^1".repeat(3000)),
+    ];
+    for input in &deep {
+        let mut calculator = Calculator::new();
+        match execute(&mut calculator, input) {
+            Err(error) => assert!(error.to_string().contains("嵌套太深"), "{}…: {}", &input[..12], error),
+            Ok(reply) => panic!("{}… 应该报错，实际得到 {:?}", &input[..12], reply),
+        }
+    }
+
+    // 限制以内的嵌套仍然可以正常求值
+    let mut calculator = Calculator::new();
+    let shallow = format!("{}1{}", "(".repeat(200), ")".repeat(200));
+    assert_eq!(evaluate(&mut calculator, &shallow), 1.0);
+    assert_eq!(evaluate(&mut calculator, &format!("{}1", "-".repeat(200))), 1.0);
+}
+
+// 只含加减乘和小整数次幂的多项式，化简后在任何点的值都不变
+fn random_polynomial(rng: &mut Rng, depth: u32) -> String {
+    if depth == 0 || rng.range(0, 3) == 0 {
+        return if rng.range(0, 2) == 0 { "x".to_string() } else { rng.range(-5, 6).to_string() };
+    }
+    let (lhs, rhs) = (random_polynomial(rng, depth - 1), random_polynomial(rng, depth - 1));
+    match rng.range(0, 4) {
+        0 => format!("({} + {})", lhs, rhs),
+        1 => format!("({} - {})", lhs, rhs),
+        2 => format!("({} * {})", lhs, rhs),
+        _ => format!("({})^{}", lhs, rng.range(0, 4)),
+    }
+}
+
+#[test]
+fn simplify_preserves_the_value_of_polynomials() {
+    let mut rng = Rng::new(6);
+    for _ in 0..CASES / 5 {
+        let mut calculator = Calculator::new();
+        let polynomial = random_polynomial(&mut rng, 3);
+        let simplified = match execute(&mut calculator, &format!("simplify({})", polynomial)) {
+            Ok(Reply::Expression(expr)) => expr.to_string(),
+            other => panic!("simplify({}) 的结果不是表达式: {:?}", polynomial, other),
+        };
+        for _ in 0..5 {
+            let x = rng.float(-3.0, 3.0);
+            calculator.store_variable("x".to_string(), Value::Number(smart_calculator::Number::Float(x)));
+            let expected = evaluate(&mut calculator, &polynomial);
+            let actual = evaluate(&mut calculator, &simplified);
+            assert_close(actual, expected, &format!("x = {} 时 {}（化简自 {}）", x, simplified, polynomial));
+        }
+    }
+}
+
+#[test]
+fn sessions_survive_a_save_and_load_round_trip() {
+    let mut rng = Rng::new(7);
+    for _ in 0..CASES / 5 {
+        let mut calculator = if rng.range(0, 2) == 0 { Calculator::new() } else { exact_calculator() };
+        for index in 0..rng.range(1, 5) {
+            let value = match rng.range(0, 4) {
+                0 => random_fraction(&mut rng),
+                1 => format!("{} km", rng.float(-100.0, 100.0)),
+                2 => format!("[{}, {}]", random_fraction(&mut rng), rng.float(-1.0, 1.0)),
+                _ => format!("{} + {}i", rng.range(-9, 10), rng.range(1, 10)),
+            };
+            execute(&mut calculator, &format!("v{} = {}", index, value)).expect("赋值");
+            execute(&mut calculator, &format!("{} * 2", value)).expect("计算");
+        }
+        execute(&mut calculator, "f(a, b) = a^2 + b").expect("定义函数");
+        execute(&mut calculator, "format sig 4").expect("设置格式");
+
+        let session = calculator.snapshot();
+        let text = session.to_text();
+        let loaded = Session::parse(&text).unwrap_or_else(|error| panic!("无法解析保存的会话: {}\n{}", error, text));
+        assert_eq!(loaded, session, "会话往返后不同:\n{}", text);
+    }
+}
+
+#[test]
+fn invalid_functions_in_a_session_file_report_the_line() {
+    for (definition, message) in [("func f(x, x) = x", "参数名重复: x"), ("func sin(x) = x", "不能重新定义内置函数: sin")] {
+        let text = format!("version 8\nvalue f:1\n{}\n", definition);
+        match Session::parse(&text) {
+            Err(error) => assert_eq!(error.to_string(), format!("会话文件第 3 行: {}", message)),
+            Ok(session) => panic!("{} 应该被拒绝，实际得到 {:?}", definition, session),
+        }
+    }
+}
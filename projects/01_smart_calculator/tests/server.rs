@@ -0,0 +1,121 @@
+// JSON 行协议测试 - 按顺序发送请求，检查每一行响应
+// 学习目标：用内存中的输入输出测试逐行协议
+
+use smart_calculator::json::{self, Json};
+use smart_calculator::server;
+
+// 把请求逐行交给 server::run，返回响应行
+fn serve(requests: &[&str]) -> Vec<String> {
+    let input = requests.join("\n");
+    let mut output = Vec::new();
+    server::run(input.as_bytes(), &mut output).expect("处理请求");
+    String::from_utf8(output).expect("响应是 UTF-8").lines().map(str::to_string).collect()
+}
+
+#[test]
+fn evaluates_requests_in_order_and_keeps_variables() {
+    let responses = serve(&[
+        r#"{"id":1,"eval":"2+2"}"#,
+        r#"{"id":2,"eval":"x = 5"}"#,
+        r#"{"id":"three","eval":"x * 3"}"#,
+        r#"{"eval":"[1, 2.5]"}"#,
+        "",
+        r##"{"id":4,"eval":"# 注释"}"##,
+        r#"{"id":5,"eval":"42"}"#,
+        r#"{"id":6,"eval":"x"}"#,
+    ]);
+    assert_eq!(
+        responses,
+        [
+            r#"{"id":1,"result":4}"#,
+            r#"{"id":2,"message":"变量 x 设置为: 5"}"#,
+            r#"{"id":"three","result":15}"#,
+            r#"{"result":[1,2.5]}"#,
+            r#"{"id":4}"#,
+            r#"{"id":5,"result":42}"#,
+            r#"{"id":6,"result":5}"#,
+        ]
+    );
+}
+
+#[test]
+fn values_without_a_json_number_are_sent_as_text() {
+    let responses = serve(&[
+        r#"{"id":1,"eval":"mode exact"}"#,
+        r#"{"id":2,"eval":"1/3 + 2^70"}"#,
+        r#"{"id":3,"eval":"2^70"}"#,
+        r#"{"id":4,"eval":"5 km"}"#,
+        r#"{"id":5,"eval":"diff(x^3, x)"}"#,
+    ]);
+    assert_eq!(responses[1], r#"{"id":2,"result":"3541774862152233910273/3"}"#);
+    assert_eq!(responses[2], r#"{"id":3,"result":1180591620717411303424}"#);
+    assert_eq!(responses[3], r#"{"id":4,"result":"5 km"}"#);
+    assert_eq!(responses[4], r#"{"id":5,"expression":"3 * x^2"}"#);
+}
+
+#[test]
+fn errors_are_reported_per_request() {
+    let responses = serve(&[
+        r#"{"id":1,"eval":"1/0"}"#,
+        r#"{"id":2,"eval":"2 +"}"#,
+        "not json",
+        r#"{"id":3}"#,
+        r#"{"id":4,"eval":"1 + 1"}"#,
+    ]);
+    assert_eq!(responses[0], r#"{"id":1,"error":{"kind":"division_by_zero","message":"除零操作"}}"#);
+    assert_eq!(
+        responses[1],
+        r#"{"id":2,"error":{"kind":"parse","message":"第 4 列: 表达式不完整","column":4}}"#
+    );
+    let error = json::parse(&responses[2]).expect("响应是 JSON");
+    assert_eq!(error.get("error").and_then(|error| error.get("kind")), Some(&Json::string("protocol")));
+    assert_eq!(responses[3], r#"{"id":3,"error":{"kind":"protocol","message":"请求需要 eval 或 reset 字段"}}"#);
+    assert_eq!(responses[4], r#"{"id":4,"result":2}"#);
+}
+
+#[test]
+fn invalid_utf8_is_rejected_and_the_session_continues() {
+    let mut input = br#"{"id":1,"eval":"x = 2"}"#.to_vec();
+    input.extend_from_slice(b"\n{\"id\":2,\"eval\":\"\xff\xfe\"}\r\n");
+    input.extend_from_slice(br#"{"id":3,"eval":"x + 1"}"#);
+    let mut output = Vec::new();
+    server::run(input.as_slice(), &mut output).expect("处理请求");
+    let responses: Vec<_> = String::from_utf8(output).expect("响应是 UTF-8").lines().map(str::to_string).collect();
+    assert_eq!(
+        responses,
+        [
+            r#"{"id":1,"message":"变量 x 设置为: 2"}"#,
+            r#"{"error":{"kind":"protocol","message":"请求不是有效的 UTF-8"}}"#,
+            r#"{"id":3,"result":3}"#,
+        ]
+    );
+}
+
+#[test]
+fn reset_discards_the_session_and_quit_stops_reading() {
+    let responses = serve(&[
+        r#"{"id":1,"eval":"x = 1"}"#,
+        r#"{"id":2,"reset":true}"#,
+        r#"{"id":3,"eval":"x"}"#,
+        r#"{"id":4,"eval":"quit"}"#,
+        r#"{"id":5,"eval":"1"}"#,
+    ]);
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[1], r#"{"id":2,"reset":true}"#);
+    assert_eq!(
+        responses[2],
+        r#"{"id":3,"error":{"kind":"undefined_variable","message":"未定义的变量: x"}}"#
+    );
+    assert_eq!(responses[3], r#"{"id":4,"quit":true}"#);
+}
+
+#[test]
+fn json_strings_round_trip_with_escapes() {
+    let text = r#"{"id":[1,{"k":null}],"eval":"a\"b\\c\n中😀","ok":false,"n":-1.5e3}"#;
+    let value = json::parse(text).expect("解析 JSON");
+    assert_eq!(value.get("eval"), Some(&Json::string("a\"b\\c\n中😀")));
+    assert_eq!(value.get("n"), Some(&Json::Number("-1.5e3".to_string())));
+    assert_eq!(json::parse(&value.to_string()), Ok(value));
+    assert!(json::parse(r#"{"a":1,}"#).is_err());
+    assert!(json::parse("[1] 2").is_err());
+}
@@ -0,0 +1,232 @@
+// 字符分类 - 按 Unicode 通用类别（General_Category）给字符分类
+// 学习目标：枚举、查表、match 中的守卫条件
+//
+// GeneralCategory 是 Unicode 定义的 29 个通用类别，CharCategory 是报告中使用的分类：
+// 在通用类别的基础上区分出汉字和表情符号，空白字符合并为一类，组合符号合并为一类。
+
+use crate::script::{script_of, Script};
+use crate::tables::{self, EXTENDED_PICTOGRAPHIC, GENERAL_CATEGORY};
+
+// Unicode 通用类别；表中没有列出的码位是 Unassigned（Cn）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneralCategory {
+    UppercaseLetter,      // Lu
+    LowercaseLetter,      // Ll
+    TitlecaseLetter,      // Lt
+    ModifierLetter,       // Lm
+    OtherLetter,          // Lo
+    NonspacingMark,       // Mn
+    SpacingMark,          // Mc
+    EnclosingMark,        // Me
+    DecimalNumber,        // Nd
+    LetterNumber,         // Nl
+    OtherNumber,          // No
+    ConnectorPunctuation, // Pc
+    DashPunctuation,      // Pd
+    OpenPunctuation,      // Ps
+    ClosePunctuation,     // Pe
+    InitialPunctuation,   // Pi
+    FinalPunctuation,     // Pf
+    OtherPunctuation,     // Po
+    MathSymbol,           // Sm
+    CurrencySymbol,       // Sc
+    ModifierSymbol,       // Sk
+    OtherSymbol,          // So
+    SpaceSeparator,       // Zs
+    LineSeparator,        // Zl
+    ParagraphSeparator,   // Zp
+    Control,              // Cc
+    Format,               // Cf
+    PrivateUse,           // Co
+    Unassigned,           // Cn
+}
+
+pub fn general_category(ch: char) -> GeneralCategory {
+    tables::lookup(GENERAL_CATEGORY, ch).unwrap_or(GeneralCategory::Unassigned)
+}
+
+// 字符类别枚举（练习枚举和模式匹配）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharCategory {
+    Uppercase,            // 大写字母
+    Lowercase,            // 小写字母
+    Titlecase,            // 首字母大写的连字，例如 ǅ
+    ModifierLetter,       // 修饰字母，例如 ʰ、々
+    Ideograph,            // 汉字
+    OtherLetter,          // 没有大小写的其他字母：假名、谚文、阿拉伯字母等
+    Mark,                 // 组合符号，例如重音符号、泰文元音符号
+    Digit,                // 十进制数字，包括其他文字的数字，例如 ٣、३
+    LetterNumber,         // 字母形式的数字，例如 Ⅻ、〇
+    OtherNumber,          // 上标、分数等其他数字，例如 ²、½、①
+    ConnectorPunctuation, // _
+    DashPunctuation,      // - — 〜
+    OpenPunctuation,      // ( [ { 「 《
+    ClosePunctuation,     // ) ] } 」 》
+    InitialPunctuation,   // “ ‘ «
+    FinalPunctuation,     // ” ’ »
+    Punctuation,          // 其他标点：. , ! ? 。，！？、
+    MathSymbol,           // + < = ∑
+    CurrencySymbol,       // $ € ¥
+    ModifierSymbol,       // ^ ` ¨
+    Emoji,                // 表情符号（Extended_Pictographic）、肤色修饰符和旗帜的区域指示符
+    OtherSymbol,          // 其他符号，例如 ° © ✓
+    Whitespace,           // 空格、制表符、换行等空白字符
+    Control,              // 其他控制字符
+    Format,               // 不可见的格式字符，例如零宽连接符
+    PrivateUse,           // 私用区字符
+    Other,                // 未分配的码位
+}
+
+impl CharCategory {
+    // 所有类别，按输出顺序排列
+    pub const ALL: [CharCategory; 27] = [
+        CharCategory::Uppercase,
+        CharCategory::Lowercase,
+        CharCategory::Titlecase,
+        CharCategory::ModifierLetter,
+        CharCategory::Ideograph,
+        CharCategory::OtherLetter,
+        CharCategory::Mark,
+        CharCategory::Digit,
+        CharCategory::LetterNumber,
+        CharCategory::OtherNumber,
+        CharCategory::ConnectorPunctuation,
+        CharCategory::DashPunctuation,
+        CharCategory::OpenPunctuation,
+        CharCategory::ClosePunctuation,
+        CharCategory::InitialPunctuation,
+        CharCategory::FinalPunctuation,
+        CharCategory::Punctuation,
+        CharCategory::MathSymbol,
+        CharCategory::CurrencySymbol,
+        CharCategory::ModifierSymbol,
+        CharCategory::Emoji,
+        CharCategory::OtherSymbol,
+        CharCategory::Whitespace,
+        CharCategory::Control,
+        CharCategory::Format,
+        CharCategory::PrivateUse,
+        CharCategory::Other,
+    ];
+}
+
+// 是否是表情符号：字母和数字（例如 ℹ、#）即使能显示为表情也按原来的类别统计
+fn is_emoji(ch: char) -> bool {
+    tables::contains(EXTENDED_PICTOGRAPHIC, ch)
+        || ('\u{1F3FB}'..='\u{1F3FF}').contains(&ch)
+        || ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+// 字符分类函数（练习模式匹配）
+pub fn categorize_character(ch: char) -> CharCategory {
+    use GeneralCategory::*;
+
+    let category = general_category(ch);
+    match category {
+        UppercaseLetter => CharCategory::Uppercase,
+        LowercaseLetter => CharCategory::Lowercase,
+        TitlecaseLetter => CharCategory::Titlecase,
+        ModifierLetter => CharCategory::ModifierLetter,
+        OtherLetter if script_of(ch) == Script::Han => CharCategory::Ideograph,
+        OtherLetter => CharCategory::OtherLetter,
+        NonspacingMark | SpacingMark | EnclosingMark => CharCategory::Mark,
+        DecimalNumber => CharCategory::Digit,
+        LetterNumber => CharCategory::LetterNumber,
+        OtherNumber => CharCategory::OtherNumber,
+        _ if is_emoji(ch) => CharCategory::Emoji,
+        ConnectorPunctuation => CharCategory::ConnectorPunctuation,
+        DashPunctuation => CharCategory::DashPunctuation,
+        OpenPunctuation => CharCategory::OpenPunctuation,
+        ClosePunctuation => CharCategory::ClosePunctuation,
+        InitialPunctuation => CharCategory::InitialPunctuation,
+        FinalPunctuation => CharCategory::FinalPunctuation,
+        OtherPunctuation => CharCategory::Punctuation,
+        MathSymbol => CharCategory::MathSymbol,
+        CurrencySymbol => CharCategory::CurrencySymbol,
+        ModifierSymbol => CharCategory::ModifierSymbol,
+        OtherSymbol => CharCategory::OtherSymbol,
+        SpaceSeparator | LineSeparator | ParagraphSeparator => CharCategory::Whitespace,
+        Control if ch.is_whitespace() => CharCategory::Whitespace,
+        Control => CharCategory::Control,
+        Format => CharCategory::Format,
+        PrivateUse => CharCategory::PrivateUse,
+        Unassigned => CharCategory::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categorizes_letters_numbers_and_punctuation() {
+        for (ch, expected) in [
+            ('A', CharCategory::Uppercase),
+            ('é', CharCategory::Lowercase),
+            ('ǅ', CharCategory::Titlecase),         // Lt
+            ('ʰ', CharCategory::ModifierLetter),    // Lm
+            ('々', CharCategory::ModifierLetter),   // Lm，不是汉字
+            ('汉', CharCategory::Ideograph),        // Lo + Han
+            ('\u{20000}', CharCategory::Ideograph), // 扩展 B 区
+            ('あ', CharCategory::OtherLetter),      // Lo + Hiragana
+            ('한', CharCategory::OtherLetter),      // Lo + Hangul
+            ('ب', CharCategory::OtherLetter),       // Lo + Arabic
+            ('\u{301}', CharCategory::Mark),        // Mn
+            ('٣', CharCategory::Digit),             // 阿拉伯-印度数字
+            ('३', CharCategory::Digit),             // 天城文数字
+            ('３', CharCategory::Digit),            // 全角数字
+            ('〇', CharCategory::LetterNumber),
+            ('½', CharCategory::OtherNumber),
+            ('_', CharCategory::ConnectorPunctuation),
+            ('-', CharCategory::DashPunctuation),
+            ('—', CharCategory::DashPunctuation),
+            ('〜', CharCategory::DashPunctuation),
+            ('(', CharCategory::OpenPunctuation),
+            ('「', CharCategory::OpenPunctuation),
+            (')', CharCategory::ClosePunctuation),
+            ('」', CharCategory::ClosePunctuation),
+            ('“', CharCategory::InitialPunctuation),
+            ('«', CharCategory::InitialPunctuation),
+            ('”', CharCategory::FinalPunctuation),
+            ('»', CharCategory::FinalPunctuation),
+            ('.', CharCategory::Punctuation),
+            ('。', CharCategory::Punctuation),
+            ('#', CharCategory::Punctuation),
+        ] {
+            assert_eq!(categorize_character(ch), expected, "U+{:04X}", ch as u32);
+        }
+    }
+
+    #[test]
+    fn categorizes_symbols_emoji_and_other_characters() {
+        for (ch, expected) in [
+            ('+', CharCategory::MathSymbol),
+            ('€', CharCategory::CurrencySymbol),
+            ('^', CharCategory::ModifierSymbol),
+            ('°', CharCategory::OtherSymbol),
+            ('😀', CharCategory::Emoji),
+            ('©', CharCategory::Emoji),             // Extended_Pictographic
+            ('\u{1F3FD}', CharCategory::Emoji),     // 肤色修饰符，通用类别是 Sk
+            ('\u{1F1E8}', CharCategory::Emoji),     // 区域指示符
+            ('ℹ', CharCategory::Lowercase),         // 字母按原来的类别统计
+            (' ', CharCategory::Whitespace),
+            ('\u{3000}', CharCategory::Whitespace), // 全角空格
+            ('\u{2028}', CharCategory::Whitespace), // Zl
+            ('\t', CharCategory::Whitespace),       // 属于空白的 Cc
+            ('\n', CharCategory::Whitespace),
+            ('\u{85}', CharCategory::Whitespace),
+            ('\0', CharCategory::Control),
+            ('\u{1B}', CharCategory::Control),
+            ('\u{7F}', CharCategory::Control),
+            ('\u{200D}', CharCategory::Format),
+            ('\u{AD}', CharCategory::Format),
+            ('\u{FEFF}', CharCategory::Format),
+            ('\u{E000}', CharCategory::PrivateUse),
+            ('\u{F0000}', CharCategory::PrivateUse),
+            ('\u{378}', CharCategory::Other),       // Cn
+            ('\u{FFFF}', CharCategory::Other),      // 非字符也是 Cn
+        ] {
+            assert_eq!(categorize_character(ch), expected, "U+{:04X}", ch as u32);
+        }
+    }
+}
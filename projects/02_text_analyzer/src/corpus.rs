@@ -0,0 +1,304 @@
+// 语料库 - 遍历目录树，用多个线程同时分析文件，再把各文件的结果合并为总计
+// 学习目标：递归遍历目录、std::thread 线程池、mpsc 通道、Arc 和 Mutex
+//
+// 主线程通过任务通道分发文件序号，工作线程分析完后把 (序号, 结果) 发回主线程；
+// 结果到达的顺序不固定，主线程先把提前到达的结果存起来，按文件顺序依次交给调用方，
+// 所以输出和合并的顺序与线程个数无关，每次运行的结果都相同。
+// 同时分发出去的文件数有上限，一个很慢的大文件不会让后面的结果在内存中无限堆积。
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use crate::stats::TextStats;
+use crate::stream::analyze_reader;
+
+// 选择文件的 glob 规则
+//   *  匹配不含 / 的任意字符，?  匹配一个不是 / 的字符，** 匹配任意多层目录
+//   不含 / 的规则匹配文件名（或目录名），含 / 的规则匹配相对于遍历起点的路径
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub include: Vec<String>, // 只分析匹配其中任意一个的文件；为空时分析全部文件
+    pub exclude: Vec<String>, // 跳过匹配其中任意一个的文件和目录
+}
+
+impl Filter {
+    // 遍历到的文件是否需要分析，relative 是相对于遍历起点的路径，用 / 分隔
+    fn accepts_file(&self, relative: &str) -> bool {
+        (self.include.is_empty() || matches_any(&self.include, relative)) && !matches_any(&self.exclude, relative)
+    }
+
+    // 遍历到的目录是否需要进入
+    fn accepts_dir(&self, relative: &str) -> bool {
+        !matches_any(&self.exclude, relative)
+    }
+}
+
+fn matches_any(patterns: &[String], relative: &str) -> bool {
+    let name = relative.rsplit('/').next().unwrap_or(relative);
+    patterns.iter().any(|pattern| {
+        let target = if pattern.contains('/') { relative } else { name };
+        let pattern: Vec<char> = pattern.trim_start_matches("./").chars().collect();
+        let target: Vec<char> = target.chars().collect();
+        glob_match(&pattern, &target)
+    })
+}
+
+// glob 匹配（练习切片模式和递归）
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', rest @ ..] => {
+            // "**/" 也可以匹配零层目录，例如 **/*.txt 匹配 a.txt
+            (rest.first() == Some(&'/') && glob_match(&rest[1..], text))
+                || (0..=text.len()).any(|skip| glob_match(rest, &text[skip..]))
+        },
+        ['*', rest @ ..] => {
+            let segment_end = text.iter().position(|&ch| ch == '/').unwrap_or(text.len());
+            (0..=segment_end).any(|skip| glob_match(rest, &text[skip..]))
+        },
+        ['?', rest @ ..] => matches!(text, [first, ..] if *first != '/') && glob_match(rest, &text[1..]),
+        [expected, rest @ ..] => text.first() == Some(expected) && glob_match(rest, &text[1..]),
+    }
+}
+
+// 收集要分析的文件：命令行中直接给出的文件总是分析，目录按名称顺序递归遍历并应用过滤规则；
+// 不进入指向目录的符号链接，避免循环。无法读取的目录记录到 errors 中，继续遍历其他目录
+pub fn collect_files(roots: &[String], filter: &Filter, errors: &mut Vec<String>) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for root in roots {
+        let path = Path::new(root);
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.is_dir() => walk(path, "", filter, &mut files, errors),
+            Ok(_) => files.push(path.to_path_buf()),
+            Err(error) => errors.push(format!("无法读取 {}: {}", root, error)),
+        }
+    }
+    files
+}
+
+fn walk(dir: &Path, relative: &str, filter: &Filter, files: &mut Vec<PathBuf>, errors: &mut Vec<String>) {
+    let entries = match fs::read_dir(dir).and_then(|entries| entries.collect::<io::Result<Vec<_>>>()) {
+        Ok(entries) => entries,
+        Err(error) => {
+            errors.push(format!("无法读取目录 {}: {}", dir.display(), error));
+            return;
+        },
+    };
+
+    let mut entries: Vec<(String, PathBuf)> = entries
+        .into_iter()
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+        .collect();
+    entries.sort();
+
+    for (name, path) in entries {
+        let child = if relative.is_empty() { name } else { format!("{}/{}", relative, name) };
+        let is_link = fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.file_type().is_symlink());
+        match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_dir() => {
+                if !is_link && filter.accepts_dir(&child) {
+                    walk(&path, &child, filter, files, errors);
+                }
+            },
+            Ok(_) => {
+                if filter.accepts_file(&child) {
+                    files.push(path);
+                }
+            },
+            Err(error) => errors.push(format!("无法读取 {}: {}", path.display(), error)),
+        }
+    }
+}
+
+// 默认的线程数：CPU 核数
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |count| count.get())
+}
+
+// 用 jobs 个线程分析所有文件，按 files 的顺序对每个文件调用一次 handle
+pub fn analyze_files(files: &[PathBuf], jobs: usize, mut handle: impl FnMut(&Path, io::Result<TextStats>)) {
+    let files: Arc<[PathBuf]> = files.into();
+    let jobs = jobs.clamp(1, files.len().max(1));
+    // 已经分发、还没有按顺序交给 handle 的文件最多这么多个：前面的大文件很慢时，
+    // 后面的结果最多堆积这么多份，工作线程暂时空闲，而不是把所有文件的结果都存在内存中
+    let window = jobs * 2;
+
+    let (job_sender, job_receiver) = mpsc::channel::<usize>();
+    let job_receiver = Arc::new(Mutex::new(job_receiver));
+    let (result_sender, result_receiver) = mpsc::channel();
+    let workers: Vec<_> = (0..jobs)
+        .map(|_| {
+            let (files, job_receiver, result_sender) = (Arc::clone(&files), Arc::clone(&job_receiver), result_sender.clone());
+            thread::spawn(move || loop {
+                // 领取下一个文件的序号，任务队列关闭后线程结束
+                let job = job_receiver.lock().expect("任务队列").recv();
+                let Ok(index) = job else {
+                    break;
+                };
+                // 分析时 panic 也要发回一个结果，否则主线程会一直等待这个文件
+                let result = panic::catch_unwind(|| File::open(&files[index]).and_then(analyze_reader))
+                    .unwrap_or_else(|_| Err(io::Error::other("分析时出现内部错误")));
+                if result_sender.send((index, result)).is_err() {
+                    break;
+                }
+            })
+        })
+        .collect();
+    drop(result_sender);
+
+    let mut dispatched = files.len().min(window);
+    for index in 0..dispatched {
+        job_sender.send(index).expect("工作线程在等待任务");
+    }
+
+    // 提前到达的结果先存起来，按顺序交给 handle；每交出一个结果再分发一个新文件
+    let mut waiting = HashMap::new();
+    let mut expected = 0;
+    for (index, result) in &result_receiver {
+        waiting.insert(index, result);
+        while let Some(result) = waiting.remove(&expected) {
+            handle(&files[expected], result);
+            expected += 1;
+            if dispatched < files.len() {
+                job_sender.send(dispatched).expect("工作线程在等待任务");
+                dispatched += 1;
+            }
+        }
+        if expected == files.len() {
+            break;
+        }
+    }
+    // 关闭任务队列，让工作线程结束
+    drop(job_sender);
+
+    for worker in workers {
+        worker.join().expect("工作线程异常退出");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glob(pattern: &str, text: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        glob_match(&pattern, &text)
+    }
+
+    fn filter(include: &[&str], exclude: &[&str]) -> Filter {
+        Filter {
+            include: include.iter().map(|pattern| pattern.to_string()).collect(),
+            exclude: exclude.iter().map(|pattern| pattern.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn matches_single_star_within_one_segment() {
+        assert!(glob("*.txt", "notes.txt"));
+        assert!(glob("*", ""));
+        assert!(glob("a?c", "abc"));
+        assert!(!glob("*.txt", "notes.md"));
+        assert!(!glob("*.txt", "docs/notes.txt"));
+        assert!(!glob("a?c", "a/c"));
+    }
+
+    #[test]
+    fn matches_double_star_across_directories() {
+        assert!(glob("**/*.txt", "a.txt"));
+        assert!(glob("**/*.txt", "a/b/c.txt"));
+        assert!(glob("docs/**", "docs/a/b.md"));
+        assert!(glob("docs/**/*.md", "docs/x.md"));
+        assert!(!glob("docs/**/*.md", "src/x.md"));
+    }
+
+    #[test]
+    fn patterns_without_slash_match_the_name() {
+        let filter = filter(&["*.txt"], &["target", "drafts/*"]);
+        assert!(filter.accepts_file("a/b/notes.txt"));
+        assert!(!filter.accepts_file("a/b/notes.md"));
+        // 不含 / 的规则匹配任意一层的目录名，含 / 的规则匹配相对路径
+        assert!(!filter.accepts_dir("target"));
+        assert!(!filter.accepts_dir("sub/target"));
+        assert!(filter.accepts_dir("sub/drafts"));
+        assert!(!filter.accepts_file("drafts/x.txt"));
+        assert!(filter.accepts_file("sub/drafts/x.txt"));
+        assert!(Filter::default().accepts_file("anything"));
+    }
+
+    // 在临时目录中创建测试用的目录树，测试结束时删除
+    struct TempTree(PathBuf);
+
+    impl TempTree {
+        fn new(name: &str, files: &[(&str, &str)]) -> TempTree {
+            let root = std::env::temp_dir().join(format!("text_analyzer_{}_{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            for (path, content) in files {
+                let path = root.join(path);
+                fs::create_dir_all(path.parent().expect("有上级目录")).expect("创建目录");
+                fs::write(path, content).expect("写入文件");
+            }
+            TempTree(root)
+        }
+
+        fn relative(&self, files: &[PathBuf]) -> Vec<String> {
+            files
+                .iter()
+                .map(|path| path.strip_prefix(&self.0).expect("在目录树中").to_string_lossy().replace('\\', "/"))
+                .collect()
+        }
+    }
+
+    impl Drop for TempTree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn walks_in_name_order_and_prunes_excluded_directories() {
+        let tree = TempTree::new(
+            "walk",
+            &[("b.txt", "b"), ("a/z.txt", "z"), ("a/y.md", "y"), ("target/x.txt", "x"), ("c/target/w.txt", "w")],
+        );
+        let roots = [tree.0.to_string_lossy().into_owned()];
+        let mut errors = Vec::new();
+
+        let files = collect_files(&roots, &Filter::default(), &mut errors);
+        assert_eq!(tree.relative(&files), ["a/y.md", "a/z.txt", "b.txt", "c/target/w.txt", "target/x.txt"]);
+
+        let files = collect_files(&roots, &filter(&["*.txt"], &["target"]), &mut errors);
+        assert_eq!(tree.relative(&files), ["a/z.txt", "b.txt"]);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn hands_results_over_in_file_order() {
+        // 第 index 个文件有 index + 1 个单词，文件越往后越大
+        let files: Vec<(String, String)> = (0..20)
+            .map(|index| (format!("{:02}.txt", index), "word ".repeat(index + 1)))
+            .collect();
+        let files: Vec<(&str, &str)> = files.iter().map(|(name, content)| (name.as_str(), content.as_str())).collect();
+        let tree = TempTree::new("order", &files);
+        let mut errors = Vec::new();
+        let mut paths = collect_files(&[tree.0.to_string_lossy().into_owned()], &Filter::default(), &mut errors);
+        paths.push(tree.0.join("missing.txt"));
+
+        let mut seen = Vec::new();
+        analyze_files(&paths, 4, |path, result| match result {
+            Ok(stats) => seen.push((path.to_path_buf(), stats.total_words)),
+            Err(_) => seen.push((path.to_path_buf(), usize::MAX)),
+        });
+        let expected: Vec<(PathBuf, usize)> = paths
+            .iter()
+            .enumerate()
+            .map(|(index, path)| (path.clone(), if index < 20 { index + 1 } else { usize::MAX }))
+            .collect();
+        assert_eq!(seen, expected);
+    }
+}
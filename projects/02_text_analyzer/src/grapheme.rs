@@ -0,0 +1,157 @@
+// 字形簇 - 按 UAX #29 的 GB 规则把文本切分为用户感知的“字符”
+// 学习目标：逐字符的状态机、字符串切片
+//
+// 一个字形簇可以由多个码位组成：é 可以写成 e + 组合重音符，🇨🇳 是两个区域指示符，
+// 👨‍👩‍👧 是三个表情符号用零宽连接符连起来，한 可以由三个谚文字母组成。
+
+use crate::tables::{self, EXTENDED_PICTOGRAPHIC, GRAPHEME_BREAK};
+
+// Grapheme_Cluster_Break 属性值；表中没有列出的字符是 Other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphemeBreak {
+    CR,
+    LF,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,   // 谚文初声
+    V,   // 谚文中声
+    T,   // 谚文终声
+    Lv,  // 没有终声的谚文音节
+    Lvt, // 有终声的谚文音节
+    Other,
+}
+
+use GraphemeBreak::*;
+
+// 谚文音节的范围，以及每个初声对应的音节数（21 个中声 × 28 种终声）
+const HANGUL_SYLLABLES: std::ops::RangeInclusive<char> = '\u{AC00}'..='\u{D7A3}';
+const HANGUL_T_COUNT: u32 = 28;
+
+pub fn grapheme_break(ch: char) -> GraphemeBreak {
+    if HANGUL_SYLLABLES.contains(&ch) {
+        // 没有终声的音节每隔 28 个出现一次
+        return if (ch as u32 - 0xAC00).is_multiple_of(HANGUL_T_COUNT) { Lv } else { Lvt };
+    }
+    tables::lookup(GRAPHEME_BREAK, ch).unwrap_or(Other)
+}
+
+// 逐字符判断边界时需要记住的状态
+#[derive(Default)]
+struct State {
+    emoji_prefix: bool,       // 前面是 Extended_Pictographic Extend*
+    emoji_joiner: bool,       // 前面是 Extended_Pictographic Extend* ZWJ（GB11）
+    regional_indicators: u32, // 前面连续的区域指示符个数（GB12、GB13）
+}
+
+// before、after 两个相邻字符之间是不是字形簇边界
+fn is_boundary(before: (char, GraphemeBreak), after: (char, GraphemeBreak), state: &State) -> bool {
+    match (before.1, after.1) {
+        (CR, LF) => false,                                                                     // GB3
+        (CR | LF | Control, _) => true,                                                        // GB4
+        (_, CR | LF | Control) => true,                                                        // GB5
+        (L, L | V | Lv | Lvt) => false,                                                        // GB6
+        (Lv | V, V | T) => false,                                                              // GB7
+        (Lvt | T, T) => false,                                                                 // GB8
+        (_, Extend | Zwj) => false,                                                            // GB9
+        (_, SpacingMark) => false,                                                             // GB9a
+        (Prepend, _) => false,                                                                 // GB9b
+        (Zwj, _) if state.emoji_joiner && is_pictographic(after.0) => false,                   // GB11
+        (RegionalIndicator, RegionalIndicator) => state.regional_indicators.is_multiple_of(2), // GB12、GB13
+        _ => true,                                                                             // GB999
+    }
+}
+
+fn is_pictographic(ch: char) -> bool {
+    tables::contains(EXTENDED_PICTOGRAPHIC, ch)
+}
+
+// 把文本切分为字形簇，返回的片段首尾相接，拼起来就是原文
+pub fn graphemes(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut chars = text.char_indices();
+    let Some((_, first)) = chars.next() else {
+        return clusters;
+    };
+
+    let mut start = 0;
+    let mut before = (first, grapheme_break(first));
+    let mut state = State::default();
+    update(&mut state, before);
+    for (index, ch) in chars {
+        let after = (ch, grapheme_break(ch));
+        if is_boundary(before, after, &state) {
+            clusters.push(&text[start..index]);
+            start = index;
+        }
+        update(&mut state, after);
+        before = after;
+    }
+    clusters.push(&text[start..]);
+    clusters
+}
+
+// 处理完一个字符后更新状态
+fn update(state: &mut State, (ch, property): (char, GraphemeBreak)) {
+    state.emoji_joiner = state.emoji_prefix && property == Zwj;
+    state.emoji_prefix = is_pictographic(ch) || (state.emoji_prefix && property == Extend);
+    state.regional_indicators = if property == RegionalIndicator { state.regional_indicators + 1 } else { 0 };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 按 GraphemeBreakTest.txt 的写法检查边界：÷ 表示边界，× 表示不是边界，中间是十六进制码位
+    fn check_breaks(line: &str) {
+        let mut text = String::new();
+        let mut expected = Vec::new();
+        let mut cluster = String::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => {
+                    if !cluster.is_empty() {
+                        expected.push(std::mem::take(&mut cluster));
+                    }
+                },
+                "×" => {},
+                code => {
+                    let ch = char::from_u32(u32::from_str_radix(code, 16).expect("十六进制码位")).expect("有效码位");
+                    text.push(ch);
+                    cluster.push(ch);
+                },
+            }
+        }
+        assert_eq!(graphemes(&text), expected, "{}", line);
+    }
+
+    #[test]
+    fn follows_grapheme_break_test_lines() {
+        for line in [
+            "÷ 000D × 000A ÷ 0061 ÷",                    // GB3
+            "÷ 000A ÷ 000D ÷",                           // GB4、GB5
+            "÷ 0061 × 0308 ÷ 0062 ÷",                    // GB9
+            "÷ 0007 ÷ 0308 ÷",                           // GB4：控制字符后面总是边界
+            "÷ 1100 × 1100 × 1161 × 11A8 ÷ 1100 ÷",      // GB6、GB8：L L V T，T 后面的 L 另起一簇
+            "÷ 1100 × AC00 × 1161 × 11A8 ÷",             // GB6、GB7：L LV V T
+            "÷ 1100 × AC01 × 11A8 × 11A8 ÷",             // GB6、GB8：L LVT T T
+            "÷ AC01 ÷ 1161 ÷",                           // LVT 后面的 V 另起一簇
+            "÷ 0600 × 0031 ÷ 0032 ÷",                    // GB9b：Prepend 和后面的字符连在一起
+            "÷ 0600 ÷ 000A ÷",                           // GB5 优先于 GB9b
+            "÷ 0915 × 093F ÷ 0915 ÷",                    // GB9a：SpacingMark
+            "÷ 0E01 × 0E33 ÷",                           // GB9a：泰文 SARA AM
+            "÷ 1F468 × 200D × 1F469 × 200D × 1F467 ÷",   // GB11
+            "÷ 2764 × FE0F × 200D × 1F525 ÷",            // GB11：ZWJ 前面可以有 Extend
+            "÷ 1F44B × 1F3FD ÷ 0061 ÷",                  // 肤色修饰符是 Extend
+            "÷ 0061 × 200D ÷ 1F469 ÷",                   // ZWJ 前面不是表情时不连接
+            "÷ 1F1E8 × 1F1F3 ÷ 1F1FA × 1F1F8 ÷ 1F1EF ÷", // GB12、GB13：区域指示符两两成对
+            "÷ 0061 ÷ 1F1E8 × 1F1F3 ÷",
+        ] {
+            check_breaks(line);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// 交互菜单 - analyzer --interactive 的编号菜单，以及函数和控制流的演示
+// 学习目标：循环读取输入、match 分派、函数参数和所有权
+
+use std::fs::File;
+use std::io::{self, Write};
+
+use crate::stats::analyze_text;
+use crate::stream::analyze_reader;
+
+// 菜单主循环（练习 loop 和 match）
+pub fn run() {
+    println!("=== Rust 文本分析工具 ===");
+    println!("这是一个练习函数、控制流和字符串处理的项目\n");
+    
+    // 显示菜单
+    show_menu();
+    
+    // 主循环
+    loop {
+        print!("\n请选择操作 > ");
+        io::stdout().flush().expect("刷新输出失败");
+        
+        let mut input = String::new();
+        // 输入结束 (Ctrl-D) 时退出，避免空转
+        if io::stdin().read_line(&mut input).expect("读取输入失败") == 0 {
+            break;
+        }
+        
+        let choice = input.trim();
+        
+        match choice {
+            "1" => analyze_user_input(),
+            "2" => analyze_file(),
+            "3" => demonstrate_functions(),
+            "4" => demonstrate_control_flow(),
+            "5" => {
+                println!("感谢使用文本分析工具！");
+                break;
+            },
+            "help" | "h" => show_menu(),
+            _ => println!("无效选择，请输入 1-5 或 help"),
+        }
+    }
+}
+
+// 显示菜单（练习函数定义）
+fn show_menu() {
+    println!("=== 功能菜单 ===");
+    println!("1. 分析用户输入的文本");
+    println!("2. 分析文件内容");
+    println!("3. 演示函数特性");
+    println!("4. 演示控制流");
+    println!("5. 退出程序");
+    println!("help/h. 显示菜单");
+}
+
+// 分析用户输入的文本
+fn analyze_user_input() {
+    println!("\n请输入要分析的文本（输入 'END' 结束）:");
+    
+    let mut text = String::new();
+    
+    // 使用 loop 读取多行输入
+    loop {
+        let mut line = String::new();
+        let read = io::stdin().read_line(&mut line)
+            .expect("读取输入失败");
+        
+        if read == 0 || line.trim() == "END" {
+            break;
+        }
+        
+        text.push_str(&line);
+    }
+    
+    if text.trim().is_empty() {
+        println!("没有输入任何文本");
+        return;
+    }
+    
+    // 分析文本并显示结果
+    let stats = analyze_text(&text);
+    stats.display();
+}
+
+// 分析文件内容
+fn analyze_file() {
+    print!("请输入文件路径: ");
+    io::stdout().flush().expect("刷新输出失败");
+    
+    let mut file_path = String::new();
+    io::stdin().read_line(&mut file_path)
+        .expect("读取输入失败");
+    
+    let file_path = file_path.trim();
+    
+    // 尝试分块读取文件（练习错误处理）
+    match File::open(file_path).and_then(analyze_reader) {
+        Ok(stats) => {
+            println!("文件读取成功，分析完成");
+            stats.display();
+        },
+        Err(error) => {
+            println!("文件读取失败: {}", error);
+        }
+    }
+}
+
+// 演示函数特性
+fn demonstrate_functions() {
+    println!("\n=== 函数特性演示 ===");
+    
+    // 无参数函数
+    print_separator();
+    
+    // 有参数函数
+    let test_text = "Hello, Rust! This is a test.";
+    println!("测试文本: \"{}\"", test_text);
+    
+    // 返回值函数
+    let word_count = count_words(test_text);
+    println!("单词数量: {}", word_count);
+    
+    // 借用参数
+    let char_count = count_characters(test_text);
+    println!("字符数量: {}", char_count);
+    
+    // 所有权转移
+    let owned_text = String::from(test_text);
+    let processed = process_text_owned(owned_text);
+    println!("处理后的文本: \"{}\"", processed);
+    
+    // 可变借用
+    let mut mutable_text = String::from("original");
+    modify_text(&mut mutable_text);
+    println!("修改后的文本: \"{}\"", mutable_text);
+    
+    print_separator();
+}
+
+// 演示控制流
+fn demonstrate_control_flow() {
+    println!("\n=== 控制流演示 ===");
+    
+    // if/else 演示
+    let number = 42;
+    if number > 50 {
+        println!("数字大于50");
+    } else if number > 30 {
+        println!("数字在30到50之间");
+    } else {
+        println!("数字小于等于30");
+    }
+    
+    // match 演示
+    let category = categorize_number(number);
+    match category {
+        "small" => println!("这是一个小数字"),
+        "medium" => println!("这是一个中等数字"),
+        "large" => println!("这是一个大数字"),
+        _ => println!("未知类别"),
+    }
+    
+    // loop 演示
+    println!("使用 loop 计数到 5:");
+    let mut counter = 0;
+    loop {
+        counter += 1;
+        print!("{} ", counter);
+        
+        if counter >= 5 {
+            break;
+        }
+    }
+    println!();
+    
+    // while 演示
+    println!("使用 while 倒数从 5:");
+    let mut countdown = 5;
+    while countdown > 0 {
+        print!("{} ", countdown);
+        countdown -= 1;
+    }
+    println!();
+    
+    // for 演示
+    println!("使用 for 遍历数组:");
+    let numbers = [1, 2, 3, 4, 5];
+    for num in numbers.iter() {
+        print!("{} ", num);
+    }
+    println!();
+    
+    // for range 演示
+    println!("使用 for range 范围:");
+    for i in 1..=5 {
+        print!("{} ", i);
+    }
+    println!();
+    
+    print_separator();
+}
+
+// 辅助函数：无参数函数示例
+fn print_separator() {
+    println!("----------------------------------------");
+}
+
+// 辅助函数：简单计数函数
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+// 辅助函数：字符计数
+fn count_characters(text: &str) -> usize {
+    text.chars().count()
+}
+
+// 辅助函数：获取所有权的函数
+fn process_text_owned(mut text: String) -> String {
+    text.push_str(" [已处理]");
+    text
+}
+
+// 辅助函数：可变借用
+fn modify_text(text: &mut String) {
+    text.push_str(" [已修改]");
+}
+
+// 辅助函数：数字分类
+fn categorize_number(num: i32) -> &'static str {
+    if num < 10 {
+        "small"
+    } else if num < 100 {
+        "medium"
+    } else {
+        "large"
+    }
+}
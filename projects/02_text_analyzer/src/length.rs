@@ -0,0 +1,161 @@
+// 文本长度 - 字节数、码位数、字形簇数和终端显示宽度
+// 学习目标：同一段文本的不同“长度”、str::len 和 chars().count() 的区别
+//
+// "héllo" 是 6 个字节、5 个码位；"e\u{301}" 是 3 个字节、2 个码位，但只是 1 个字形簇；
+// "中文" 是 6 个字节、2 个码位、2 个字形簇，在终端中占 4 列。
+
+use std::ops::AddAssign;
+
+use crate::category::{general_category, GeneralCategory};
+use crate::grapheme;
+use crate::tables::{self, WIDE};
+
+// 报告中使用的长度单位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    Bytes,     // UTF-8 字节
+    Chars,     // Unicode 码位，即 char
+    Graphemes, // 字形簇，用户看到的一个“字符”
+    Width,     // 终端中占的列数
+}
+
+impl LengthUnit {
+    pub const ALL: [LengthUnit; 4] = [LengthUnit::Bytes, LengthUnit::Chars, LengthUnit::Graphemes, LengthUnit::Width];
+
+    // 命令行参数中的名称
+    pub fn parse(name: &str) -> Option<LengthUnit> {
+        match name {
+            "bytes" => Some(LengthUnit::Bytes),
+            "chars" => Some(LengthUnit::Chars),
+            "graphemes" => Some(LengthUnit::Graphemes),
+            "width" => Some(LengthUnit::Width),
+            _ => None,
+        }
+    }
+
+    // 报告中的单位名称
+    pub fn label(self) -> &'static str {
+        match self {
+            LengthUnit::Bytes => "字节",
+            LengthUnit::Chars => "码位",
+            LengthUnit::Graphemes => "字形簇",
+            LengthUnit::Width => "列",
+        }
+    }
+
+    // 在按单位排列的数组中的下标
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+// 一段文本用四种单位计量的长度
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Lengths {
+    pub bytes: usize,
+    pub chars: usize,
+    pub graphemes: usize,
+    pub width: usize,
+}
+
+impl Lengths {
+    pub fn of(text: &str) -> Self {
+        let clusters = grapheme::graphemes(text);
+        Lengths {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            graphemes: clusters.len(),
+            width: clusters.iter().map(|cluster| cluster_width(cluster)).sum(),
+        }
+    }
+
+    pub fn get(&self, unit: LengthUnit) -> usize {
+        match unit {
+            LengthUnit::Bytes => self.bytes,
+            LengthUnit::Chars => self.chars,
+            LengthUnit::Graphemes => self.graphemes,
+            LengthUnit::Width => self.width,
+        }
+    }
+}
+
+impl AddAssign for Lengths {
+    fn add_assign(&mut self, other: Lengths) {
+        self.bytes += other.bytes;
+        self.chars += other.chars;
+        self.graphemes += other.graphemes;
+        self.width += other.width;
+    }
+}
+
+// 单个字符占的列数：控制字符、组合符号和格式字符不占位置，东亚宽字符和全角字符占两列
+pub fn char_width(ch: char) -> usize {
+    use GeneralCategory::*;
+
+    match general_category(ch) {
+        Control | NonspacingMark | EnclosingMark | LineSeparator | ParagraphSeparator => 0,
+        Format if ch != '\u{AD}' => 0,
+        // 谚文的中声和终声字母和前面的初声组合在一起显示
+        _ if ('\u{1160}'..='\u{11FF}').contains(&ch) || ('\u{D7B0}'..='\u{D7FF}').contains(&ch) => 0,
+        _ if tables::contains(WIDE, ch) => 2,
+        _ => 1,
+    }
+}
+
+// 字形簇占的列数：取其中最宽的字符；带 U+FE0F（表情样式）的字符和旗帜按表情显示，占两列
+pub fn cluster_width(cluster: &str) -> usize {
+    let widest = cluster.chars().map(char_width).max().unwrap_or(0);
+    let emoji_style = cluster.contains('\u{FE0F}')
+        || cluster.chars().next().is_some_and(|ch| ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch));
+    if emoji_style && widest > 0 { 2 } else { widest }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_cluster_widths() {
+        for (cluster, expected) in [
+            ("a", 1),
+            ("汉", 2),                 // 东亚宽字符
+            ("Ａ", 2),                 // 全角字母
+            ("ｱ", 1),                  // 半角片假名
+            ("e\u{301}", 1),           // 组合符号不占位置
+            ("\u{301}", 0),
+            ("한", 2),
+            ("\u{1100}\u{1161}", 2),   // 初声 + 中声
+            ("\u{200D}", 0),
+            ("\t", 0),
+            ("\u{AD}", 1),             // 软连字符按一列显示
+            ("😀", 2),
+            ("\u{2764}", 1),           // 默认按文本样式显示
+            ("\u{2764}\u{FE0F}", 2),   // VS16 要求表情样式
+            ("\u{1F1E8}\u{1F1F3}", 2), // 旗帜
+            ("\u{1F1E8}", 2),          // 单个区域指示符
+            ("\u{1F44B}\u{1F3FD}", 2), // 肤色修饰符
+            ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 2),
+        ] {
+            assert_eq!(cluster_width(cluster), expected, "{:?}", cluster);
+        }
+    }
+
+    #[test]
+    fn measures_text_in_every_unit() {
+        for (text, bytes, chars, graphemes, width) in [
+            ("héllo", 6, 5, 5, 5),
+            ("e\u{301}", 3, 2, 1, 1),
+            ("汉字", 6, 2, 2, 4),
+            ("\r\n", 2, 2, 1, 0),
+            ("\u{2764}\u{FE0F}", 6, 2, 1, 2),
+            ("\u{1F1E8}\u{1F1F3}\u{1F1FA}", 12, 3, 2, 4),
+            ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 18, 5, 1, 2),
+        ] {
+            let expected = Lengths { bytes, chars, graphemes, width };
+            assert_eq!(Lengths::of(text), expected, "{:?}", text);
+            for unit in LengthUnit::ALL {
+                assert_eq!(Lengths::of(text).get(unit), [bytes, chars, graphemes, width][unit.index()]);
+            }
+        }
+    }
+}
@@ -1,432 +1,233 @@
 // 文本分析工具 - Rust 函数和控制流练习项目
 // 学习目标：函数设计、控制流、字符串处理、基础所有权
-
-use std::collections::HashMap;
-use std::fs;
+// 统计逻辑在 src/stats.rs 中，分块读取在 src/stream.rs 中，目录遍历和多线程分析在 src/corpus.rs 中，
+// 分词在 src/segment.rs 中，编号菜单和演示在 src/interactive.rs 中，
+// 这里只负责命令行参数和输出
+
+mod category;
+mod corpus;
+mod grapheme;
+mod interactive;
+mod length;
+mod script;
+mod segment;
+mod stats;
+mod stream;
+mod tables;
+
+use std::env;
+use std::fs::File;
 use std::io;
-
-// 字符类别枚举（练习枚举和模式匹配）
-#[derive(Debug, PartialEq)]
-enum CharCategory {
-    Uppercase,
-    Lowercase,
-    Digit,
-    Punctuation,
-    Whitespace,
-    Other,
-}
-
-// 文本统计结构体（练习结构体定义）
-#[derive(Debug, Default)]
-struct TextStats {
-    total_chars: usize,
-    total_words: usize,
-    total_lines: usize,
-    total_sentences: usize,
-    char_categories: HashMap<CharCategory, usize>,
-    word_frequencies: HashMap<String, usize>,
-    longest_word: String,
-    shortest_word: String,
-    average_word_length: f64,
-}
-
-impl TextStats {
-    // 创建新的统计实例
-    fn new() -> Self {
-        Self {
-            char_categories: HashMap::new(),
-            word_frequencies: HashMap::new(),
-            ..Default::default()
-        }
-    }
-    
-    // 显示统计结果
-    fn display(&self) {
-        println!("\n=== 文本分析结果 ===");
-        println!("字符总数: {}", self.total_chars);
-        println!("单词总数: {}", self.total_words);
-        println!("行数总数: {}", self.total_lines);
-        println!("句子总数: {}", self.total_sentences);
-        println!("平均单词长度: {:.2}", self.average_word_length);
-        
-        if !self.longest_word.is_empty() {
-            println!("最长单词: {} ({} 字符)", self.longest_word, self.longest_word.len());
-        }
-        
-        if !self.shortest_word.is_empty() {
-            println!("最短单词: {} ({} 字符)", self.shortest_word, self.shortest_word.len());
-        }
-        
-        println!("\n=== 字符分类统计 ===");
-        for (category, count) in &self.char_categories {
-            println!("{:?}: {}", category, count);
-        }
-        
-        println!("\n=== 高频词汇 (前10个) ===");
-        let mut word_vec: Vec<_> = self.word_frequencies.iter().collect();
-        word_vec.sort_by(|a, b| b.1.cmp(a.1));
-        
-        for (word, frequency) in word_vec.iter().take(10) {
-            println!("{}: {} 次", word, frequency);
-        }
-    }
-}
+use std::path::Path;
+use std::process;
+
+use corpus::Filter;
+use length::LengthUnit;
+use stats::{Sections, TextStats, DEFAULT_TOP, DEFAULT_UNIT};
+
+// 命令行选项
+struct Options {
+    files: Vec<String>, // 按命令行顺序分析，"-" 表示标准输入；语料库模式下是要遍历的目录
+    sections: Sections, // 要输出的统计部分
+    top: usize,         // 高频词的个数
+    unit: LengthUnit,   // 单词长度的单位
+    corpus: bool,       // 语料库模式：遍历目录，多线程分析，最后输出合计
+    filter: Filter,     // 语料库模式下选择文件的 glob 规则
+    jobs: usize,        // 语料库模式下的线程数
+}
+
+const USAGE: &str = "用法:
+  analyzer [选项] [文件...]     分析文件，- 表示标准输入；没有指定文件时读取标准输入
+  analyzer --corpus [目录...]   遍历目录（默认当前目录），多线程分析每个文件，输出各文件和合计的结果
+  analyzer --interactive        编号菜单模式
+选项（不选择统计部分时全部输出）:
+  -l, --lines                   行数和句子数
+  -w, --words                   单词数、平均长度、最长和最短单词
+  -c, --chars                   字节数、码位数、字形簇数、显示宽度和字符分类
+  -f, --frequencies             高频词
+  -n, --top N                   高频词的个数（默认 10，同时选择 --frequencies）
+  -u, --unit 单位               单词长度的单位：bytes 字节、chars 码位、graphemes 字形簇（默认）、
+                                width 终端显示宽度
+  -r, --corpus                  语料库模式
+      --include 规则            只分析匹配 glob 规则的文件，可以多次使用（同时选择 --corpus）
+      --exclude 规则            跳过匹配 glob 规则的文件和目录，可以多次使用（同时选择 --corpus）
+                                规则中 * 匹配不含 / 的任意字符，? 匹配一个字符，** 匹配任意多层目录；
+                                不含 / 的规则匹配文件名，例如 --include '*.txt' --exclude target
+  -j, --jobs N                  分析文件的线程数（默认 CPU 核数，同时选择 --corpus）
+  -i, --interactive             编号菜单模式，不能和其他参数一起使用
+  -h, --help                    显示本帮助
+退出码: 0 成功，1 有文件无法读取，2 参数错误";
 
 fn main() {
-    println!("=== Rust 文本分析工具 ===");
-    println!("这是一个练习函数、控制流和字符串处理的项目\n");
-    
-    // 显示菜单
-    show_menu();
-    
-    // 主循环
-    loop {
-        print!("\n请选择操作 > ");
-        
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)
-            .expect("读取输入失败");
-        
-        let choice = input.trim();
-        
-        match choice {
-            "1" => analyze_user_input(),
-            "2" => analyze_file(),
-            "3" => demonstrate_functions(),
-            "4" => demonstrate_control_flow(),
-            "5" => {
-                println!("感谢使用文本分析工具！");
-                break;
-            },
-            "help" | "h" => show_menu(),
-            _ => println!("无效选择，请输入 1-5 或 help"),
-        }
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
     }
-}
-
-// 显示菜单（练习函数定义）
-fn show_menu() {
-    println!("=== 功能菜单 ===");
-    println!("1. 分析用户输入的文本");
-    println!("2. 分析文件内容");
-    println!("3. 演示函数特性");
-    println!("4. 演示控制流");
-    println!("5. 退出程序");
-    println!("help/h. 显示菜单");
-}
-
-// 分析用户输入的文本
-fn analyze_user_input() {
-    println!("\n请输入要分析的文本（输入 'END' 结束）:");
-    
-    let mut text = String::new();
-    
-    // 使用 loop 读取多行输入
-    loop {
-        let mut line = String::new();
-        io::stdin().read_line(&mut line)
-            .expect("读取输入失败");
-        
-        if line.trim() == "END" {
-            break;
+    if args.iter().any(|arg| arg == "-i" || arg == "--interactive") {
+        if args.len() > 1 {
+            eprintln!("错误: --interactive 不能和其他参数一起使用\n{}", USAGE);
+            process::exit(2);
         }
-        
-        text.push_str(&line);
-    }
-    
-    if text.trim().is_empty() {
-        println!("没有输入任何文本");
+        interactive::run();
         return;
     }
-    
-    // 分析文本并显示结果
-    let stats = analyze_text(&text);
-    stats.display();
-}
 
-// 分析文件内容
-fn analyze_file() {
-    print!("请输入文件路径: ");
-    
-    let mut file_path = String::new();
-    io::stdin().read_line(&mut file_path)
-        .expect("读取输入失败");
-    
-    let file_path = file_path.trim();
-    
-    // 尝试读取文件（练习错误处理）
-    match fs::read_to_string(file_path) {
-        Ok(content) => {
-            println!("文件读取成功，开始分析...");
-            let stats = analyze_text(&content);
-            stats.display();
-        },
+    match parse_args(&args) {
+        Ok(options) => process::exit(run(&options)),
         Err(error) => {
-            println!("文件读取失败: {}", error);
-        }
-    }
-}
-
-// 主要的文本分析函数（练习借用和所有权）
-fn analyze_text(text: &str) -> TextStats {
-    let mut stats = TextStats::new();
-    
-    // 统计总字符数
-    stats.total_chars = text.chars().count();
-    
-    // 统计行数
-    stats.total_lines = count_lines(text);
-    
-    // 统计句子数
-    stats.total_sentences = count_sentences(text);
-    
-    // 分析字符类别
-    analyze_characters(text, &mut stats);
-    
-    // 分析单词
-    analyze_words(text, &mut stats);
-    
-    stats
-}
-
-// 统计行数（练习简单函数）
-fn count_lines(text: &str) -> usize {
-    if text.is_empty() {
-        0
-    } else {
-        text.lines().count()
+            eprintln!("错误: {}\n{}", error, USAGE);
+            process::exit(2);
+        },
     }
 }
 
-// 统计句子数（练习字符处理）
-fn count_sentences(text: &str) -> usize {
-    let mut count = 0;
-    
-    for ch in text.chars() {
-        // 使用 match 进行模式匹配
-        match ch {
-            '.' | '!' | '?' => count += 1,
-            _ => continue,
+// 解析命令行参数
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        files: Vec::new(),
+        sections: Sections::none(),
+        top: DEFAULT_TOP,
+        unit: DEFAULT_UNIT,
+        corpus: false,
+        filter: Filter::default(),
+        jobs: corpus::default_jobs(),
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-l" | "--lines" => options.sections.lines = true,
+            "-w" | "--words" => options.sections.words = true,
+            "-c" | "--chars" => options.sections.characters = true,
+            "-f" | "--frequencies" => options.sections.frequencies = true,
+            "-n" | "--top" => {
+                let value = args.next().ok_or_else(|| format!("{} 后面缺少个数", arg))?;
+                options.top = value
+                    .parse()
+                    .map_err(|_| format!("{} 的值必须是非负整数: {}", arg, value))?;
+                options.sections.frequencies = true;
+            },
+            "-u" | "--unit" => {
+                let value = args.next().ok_or_else(|| format!("{} 后面缺少单位", arg))?;
+                options.unit = LengthUnit::parse(value)
+                    .ok_or_else(|| format!("未知的单位: {}（可以是 bytes、chars、graphemes、width）", value))?;
+            },
+            "-r" | "--corpus" => options.corpus = true,
+            "--include" | "--exclude" => {
+                let value = args.next().ok_or_else(|| format!("{} 后面缺少规则", arg))?;
+                let patterns = if arg == "--include" { &mut options.filter.include } else { &mut options.filter.exclude };
+                patterns.push(value.clone());
+                options.corpus = true;
+            },
+            "-j" | "--jobs" => {
+                let value = args.next().ok_or_else(|| format!("{} 后面缺少线程数", arg))?;
+                options.jobs = value
+                    .parse()
+                    .ok()
+                    .filter(|&jobs| jobs > 0)
+                    .ok_or_else(|| format!("{} 的值必须是正整数: {}", arg, value))?;
+                options.corpus = true;
+            },
+            "-" => options.files.push(arg.clone()),
+            _ if arg.starts_with('-') => return Err(format!("未知的选项: {}", arg)),
+            _ => options.files.push(arg.clone()),
         }
     }
-    
-    count
-}
 
-// 分析字符类别（练习字符分类和HashMap）
-fn analyze_characters(text: &str, stats: &mut TextStats) {
-    for ch in text.chars() {
-        let category = categorize_character(ch);
-        
-        // 更新字符类别计数
-        let count = stats.char_categories.entry(category).or_insert(0);
-        *count += 1;
+    if options.sections.is_empty() {
+        options.sections = Sections::all();
     }
-}
-
-// 字符分类函数（练习模式匹配）
-fn categorize_character(ch: char) -> CharCategory {
-    match ch {
-        'a'..='z' => CharCategory::Lowercase,
-        'A'..='Z' => CharCategory::Uppercase,
-        '0'..='9' => CharCategory::Digit,
-        ' ' | '\t' | '\n' | '\r' => CharCategory::Whitespace,
-        '.' | ',' | '!' | '?' | ';' | ':' | '"' | '\'' => CharCategory::Punctuation,
-        _ => CharCategory::Other,
-    }
-}
-
-// 分析单词（练习字符串处理和迭代）
-fn analyze_words(text: &str, stats: &mut TextStats) {
-    let words: Vec<String> = extract_words(text);
-    
-    if words.is_empty() {
-        return;
-    }
-    
-    stats.total_words = words.len();
-    
-    // 计算平均单词长度
-    let total_length: usize = words.iter().map(|w| w.len()).sum();
-    stats.average_word_length = total_length as f64 / words.len() as f64;
-    
-    // 找最长和最短单词
-    find_extremes(&words, stats);
-    
-    // 计算词频
-    calculate_word_frequencies(&words, stats);
-}
-
-// 提取单词（练习字符串分割和过滤）
-fn extract_words(text: &str) -> Vec<String> {
-    text.split_whitespace()
-        .map(|word| clean_word(word))
-        .filter(|word| !word.is_empty())
-        .collect()
-}
-
-// 清洗单词（移除标点符号）
-fn clean_word(word: &str) -> String {
-    word.chars()
-        .filter(|ch| ch.is_alphabetic())
-        .collect::<String>()
-        .to_lowercase()
-}
-
-// 找到最长和最短单词（练习迭代器和比较）
-fn find_extremes(words: &[String], stats: &mut TextStats) {
-    if let Some(first_word) = words.first() {
-        stats.longest_word = first_word.clone();
-        stats.shortest_word = first_word.clone();
-        
-        for word in words {
-            if word.len() > stats.longest_word.len() {
-                stats.longest_word = word.clone();
-            }
-            
-            if word.len() < stats.shortest_word.len() {
-                stats.shortest_word = word.clone();
-            }
+    if options.corpus {
+        if options.files.iter().any(|file| file == "-") {
+            return Err("语料库模式不能读取标准输入".to_string());
         }
-    }
-}
+        if options.files.is_empty() {
+            options.files.push(".".to_string());
+        }
+    } else if options.files.is_empty() {
+        options.files.push("-".to_string());
+    }
+    Ok(options)
+}
+
+// 依次分析每个文件并输出结果，无法读取的文件报告到标准错误后继续；返回进程退出码
+fn run(options: &Options) -> i32 {
+    if options.corpus {
+        return run_corpus(options);
+    }
+
+    let mut failed = false;
+    let show_names = options.files.len() > 1;
+
+    for (index, path) in options.files.iter().enumerate() {
+        // 分块读取，大文件也不会一次读入内存
+        let stats = if path == "-" {
+            stream::analyze_reader(io::stdin().lock()).map_err(|error| format!("无法读取标准输入: {}", error))
+        } else {
+            File::open(path)
+                .and_then(stream::analyze_reader)
+                .map_err(|error| format!("无法读取 {}: {}", path, error))
+        };
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(error) => {
+                eprintln!("错误: {}", error);
+                failed = true;
+                continue;
+            },
+        };
 
-// 计算单词频率（练习HashMap操作）
-fn calculate_word_frequencies(words: &[String], stats: &mut TextStats) {
-    for word in words {
-        let count = stats.word_frequencies.entry(word.clone()).or_insert(0);
-        *count += 1;
+        // 多个文件时用文件名分隔各自的结果
+        let name = if path == "-" { "(标准输入)" } else { path.as_str() };
+        print_report(show_names.then_some(name), index == 0, &stats, options);
     }
-}
 
-// 演示函数特性
-fn demonstrate_functions() {
-    println!("\n=== 函数特性演示 ===");
-    
-    // 无参数函数
-    print_separator();
-    
-    // 有参数函数
-    let test_text = "Hello, Rust! This is a test.";
-    println!("测试文本: \"{}\"", test_text);
-    
-    // 返回值函数
-    let word_count = count_words(test_text);
-    println!("单词数量: {}", word_count);
-    
-    // 借用参数
-    let char_count = count_characters(test_text);
-    println!("字符数量: {}", char_count);
-    
-    // 所有权转移
-    let owned_text = String::from(test_text);
-    let processed = process_text_owned(owned_text);
-    println!("处理后的文本: \"{}\"", processed);
-    
-    // 可变借用
-    let mut mutable_text = String::from("original");
-    modify_text(&mut mutable_text);
-    println!("修改后的文本: \"{}\"", mutable_text);
-    
-    print_separator();
+    if failed { 1 } else { 0 }
 }
 
-// 演示控制流
-fn demonstrate_control_flow() {
-    println!("\n=== 控制流演示 ===");
-    
-    // if/else 演示
-    let number = 42;
-    if number > 50 {
-        println!("数字大于50");
-    } else if number > 30 {
-        println!("数字在30到50之间");
-    } else {
-        println!("数字小于等于30");
+// 语料库模式：遍历目录，多线程分析，按路径顺序输出每个文件的结果，最后输出合计
+fn run_corpus(options: &Options) -> i32 {
+    let mut errors = Vec::new();
+    let files = corpus::collect_files(&options.files, &options.filter, &mut errors);
+    let mut failed = !errors.is_empty();
+    for error in errors {
+        eprintln!("错误: {}", error);
     }
-    
-    // match 演示
-    let category = categorize_number(number);
-    match category {
-        "small" => println!("这是一个小数字"),
-        "medium" => println!("这是一个中等数字"),
-        "large" => println!("这是一个大数字"),
-        _ => println!("未知类别"),
+    if files.is_empty() {
+        eprintln!("没有找到要分析的文件");
+        return if failed { 1 } else { 0 };
     }
-    
-    // loop 演示
-    println!("使用 loop 计数到 5:");
-    let mut counter = 0;
-    loop {
-        counter += 1;
-        print!("{} ", counter);
-        
-        if counter >= 5 {
-            break;
-        }
-    }
-    println!();
-    
-    // while 演示
-    println!("使用 while 倒数从 5:");
-    let mut countdown = 5;
-    while countdown > 0 {
-        print!("{} ", countdown);
-        countdown -= 1;
-    }
-    println!();
-    
-    // for 演示
-    println!("使用 for 遍历数组:");
-    let numbers = [1, 2, 3, 4, 5];
-    for num in numbers.iter() {
-        print!("{} ", num);
-    }
-    println!();
-    
-    // for range 演示
-    println!("使用 for range 范围:");
-    for i in 1..=5 {
-        print!("{} ", i);
-    }
-    println!();
-    
-    print_separator();
-}
-
-// 辅助函数：无参数函数示例
-fn print_separator() {
-    println!("----------------------------------------");
-}
 
-// 辅助函数：简单计数函数
-fn count_words(text: &str) -> usize {
-    text.split_whitespace().count()
-}
-
-// 辅助函数：字符计数
-fn count_characters(text: &str) -> usize {
-    text.chars().count()
-}
+    let mut total = TextStats::new();
+    let mut analyzed = 0;
+    corpus::analyze_files(&files, options.jobs, |path: &Path, result| match result {
+        Ok(stats) => {
+            print_report(Some(&path.display().to_string()), analyzed == 0, &stats, options);
+            total.merge(stats);
+            analyzed += 1;
+        },
+        Err(error) => {
+            eprintln!("错误: 无法读取 {}: {}", path.display(), error);
+            failed = true;
+        },
+    });
 
-// 辅助函数：获取所有权的函数
-fn process_text_owned(mut text: String) -> String {
-    text.push_str(" [已处理]");
-    text
-}
+    let title = format!("合计（{} 个文件）", analyzed);
+    print_report(Some(&title), analyzed == 0, &total, options);
 
-// 辅助函数：可变借用
-fn modify_text(text: &mut String) {
-    text.push_str(" [已修改]");
+    if failed { 1 } else { 0 }
 }
 
-// 辅助函数：数字分类
-fn categorize_number(num: i32) -> &'static str {
-    if num < 10 {
-        "small"
-    } else if num < 100 {
-        "medium"
-    } else {
-        "large"
+// 输出一份结果；有 title 时先输出 "=== title ===" 标题，不是第一份结果时用空行隔开
+fn print_report(title: Option<&str>, first: bool, stats: &TextStats, options: &Options) {
+    if let Some(title) = title {
+        if !first {
+            println!();
+        }
+        println!("=== {} ===", title);
+    }
+    for line in stats.report(&options.sections, options.top, options.unit) {
+        println!("{}", line);
     }
 }
@@ -0,0 +1,75 @@
+// 文字系统 - 判断字符和单词属于哪种文字（拉丁字母、汉字、假名等）
+// 学习目标：枚举的关联常量、查表、Option 的默认值
+
+use crate::tables::{self, SCRIPT};
+
+// 常见的文字系统；表中没有列出的字母归为 Other，数字和标点等归为 Common
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Thai,
+    Lao,
+    Myanmar,
+    Khmer,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+    Other,
+    Common,
+}
+
+impl Script {
+    // 所有文字系统，按输出顺序排列
+    pub const ALL: [Script; 19] = [
+        Script::Latin,
+        Script::Greek,
+        Script::Cyrillic,
+        Script::Armenian,
+        Script::Hebrew,
+        Script::Arabic,
+        Script::Devanagari,
+        Script::Bengali,
+        Script::Thai,
+        Script::Lao,
+        Script::Myanmar,
+        Script::Khmer,
+        Script::Georgian,
+        Script::Hangul,
+        Script::Hiragana,
+        Script::Katakana,
+        Script::Han,
+        Script::Other,
+        Script::Common,
+    ];
+
+    // 不用空格分隔单词、需要词典才能切分的文字
+    pub fn is_complex(self) -> bool {
+        matches!(self, Script::Thai | Script::Lao | Script::Myanmar | Script::Khmer)
+    }
+}
+
+// 字符所属的文字系统
+pub fn script_of(ch: char) -> Script {
+    match tables::lookup(SCRIPT, ch) {
+        Some(script) => script,
+        None if ch.is_alphabetic() => Script::Other,
+        None => Script::Common,
+    }
+}
+
+// 单词所属的文字系统：第一个不是通用字符的字符决定，例如 "3D" 属于 Latin
+pub fn word_script(word: &str) -> Script {
+    word.chars()
+        .map(script_of)
+        .find(|&script| script != Script::Common)
+        .unwrap_or(Script::Common)
+}
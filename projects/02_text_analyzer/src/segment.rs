@@ -0,0 +1,369 @@
+// 分词 - 按 Unicode 单词边界（UAX #29）切分文本，汉字和假名等没有空格的文字另外处理
+// 学习目标：按规则表实现状态判断、字节下标和字符串切片、生命周期
+//
+// 第一步按 UAX #29 的 WB1–WB999 规则找出单词边界，例如 "don't" 是一个单词，"e-mail" 是 "e"、"-"、"mail"。
+// UAX #29 把每个汉字、平假名都切成单独的片段，所以第二步把相邻的同类片段合并：
+//   - 汉字：用内置词表（src/cjk_words.txt）做正向最大匹配，词表中没有的字单独成词
+//   - 平假名，以及泰文、老挝文、缅甸文、高棉文：没有词典，每一段连续的文字算一个词
+// 最后只保留含有字母（包括汉字、假名）的片段，空白、标点和纯数字不算单词。
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use crate::grapheme::{grapheme_break, GraphemeBreak};
+use crate::script::{script_of, Script};
+use crate::tables::{self, EXTENDED_PICTOGRAPHIC, WORD_BREAK};
+
+// Word_Break 属性值；表中没有列出的字符是 Other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordBreak {
+    CR,
+    LF,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+    Other,
+}
+
+use WordBreak::*;
+
+fn word_break(ch: char) -> WordBreak {
+    tables::lookup(WORD_BREAK, ch).unwrap_or(Other)
+}
+
+// WB4 中被忽略、附着在前一个字符上的属性
+fn is_ignorable(property: WordBreak) -> bool {
+    matches!(property, Extend | Format | Zwj)
+}
+
+fn is_newline(property: WordBreak) -> bool {
+    matches!(property, CR | LF | Newline)
+}
+
+// AHLetter
+fn is_letter(property: WordBreak) -> bool {
+    matches!(property, ALetter | HebrewLetter)
+}
+
+// MidNumLetQ
+fn is_mid_num_let(property: WordBreak) -> bool {
+    matches!(property, MidNumLet | SingleQuote)
+}
+
+// 按单词边界切分文本，返回的片段首尾相接，拼起来就是原文
+pub fn split_word_bounds(text: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let properties: Vec<WordBreak> = chars.iter().map(|&(_, ch)| word_break(ch)).collect();
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for (position, &(end, ch)) in chars.iter().enumerate().skip(1) {
+        if is_boundary(&properties, position, ch) {
+            segments.push(&text[start..end]);
+            start = end;
+        }
+    }
+    if start < text.len() {
+        segments.push(&text[start..]);
+    }
+    segments
+}
+
+// position 之前的有效字符：跳过 WB4 中附着的字符，但不会越过换行
+fn previous_effective(properties: &[WordBreak], position: usize) -> Option<usize> {
+    let mut index = position.checked_sub(1)?;
+    while index > 0 && is_ignorable(properties[index]) && !is_newline(properties[index - 1]) {
+        index -= 1;
+    }
+    Some(index)
+}
+
+// position 之后（不含）的有效字符
+fn next_effective(properties: &[WordBreak], position: usize) -> Option<WordBreak> {
+    properties[position + 1..].iter().copied().find(|&property| !is_ignorable(property))
+}
+
+// 第 position 个字符（ch）之前是不是单词边界（WB1、WB2 由调用方处理）
+fn is_boundary(properties: &[WordBreak], position: usize, ch: char) -> bool {
+    let before = properties[position - 1];
+    let current = properties[position];
+
+    match (before, current) {
+        (CR, LF) => return false,                                                // WB3
+        (before, _) if is_newline(before) => return true,                        // WB3a
+        (_, current) if is_newline(current) => return true,                      // WB3b
+        (Zwj, _) if tables::contains(EXTENDED_PICTOGRAPHIC, ch) => return false, // WB3c
+        (WSegSpace, WSegSpace) => return false,                                  // WB3d
+        (_, current) if is_ignorable(current) => return false,                   // WB4
+        _ => {},
+    }
+
+    // 之后的规则比较忽略了 Extend、Format、ZWJ 的字符序列
+    let Some(left_index) = previous_effective(properties, position) else {
+        return true;
+    };
+    let left = properties[left_index];
+    let left2 = previous_effective(properties, left_index).map(|index| properties[index]);
+    let right = current;
+    let right2 = next_effective(properties, position);
+    let right2_is = |test: fn(WordBreak) -> bool| right2.is_some_and(test);
+    let left2_is = |test: fn(WordBreak) -> bool| left2.is_some_and(test);
+
+    if is_letter(left) && is_letter(right) {
+        return false; // WB5
+    }
+    if is_letter(left) && (right == MidLetter || is_mid_num_let(right)) && right2_is(is_letter) {
+        return false; // WB6
+    }
+    if left2_is(is_letter) && (left == MidLetter || is_mid_num_let(left)) && is_letter(right) {
+        return false; // WB7
+    }
+    if left == HebrewLetter && right == SingleQuote {
+        return false; // WB7a
+    }
+    if left == HebrewLetter && right == DoubleQuote && right2 == Some(HebrewLetter) {
+        return false; // WB7b
+    }
+    if left2 == Some(HebrewLetter) && left == DoubleQuote && right == HebrewLetter {
+        return false; // WB7c
+    }
+    if (left == Numeric || is_letter(left)) && (right == Numeric || is_letter(right)) {
+        return false; // WB8、WB9、WB10
+    }
+    if left2 == Some(Numeric) && (left == MidNum || is_mid_num_let(left)) && right == Numeric {
+        return false; // WB11
+    }
+    if left == Numeric && (right == MidNum || is_mid_num_let(right)) && right2 == Some(Numeric) {
+        return false; // WB12
+    }
+    if left == Katakana && right == Katakana {
+        return false; // WB13
+    }
+    if (is_letter(left) || matches!(left, Numeric | Katakana | ExtendNumLet)) && right == ExtendNumLet {
+        return false; // WB13a
+    }
+    if left == ExtendNumLet && (is_letter(right) || matches!(right, Numeric | Katakana)) {
+        return false; // WB13b
+    }
+    if left == RegionalIndicator && right == RegionalIndicator {
+        // WB15、WB16：区域指示符两两组成一面旗帜
+        let mut count = 0;
+        let mut index = Some(left_index);
+        while let Some(current) = index.filter(|&index| properties[index] == RegionalIndicator) {
+            count += 1;
+            index = previous_effective(properties, current);
+        }
+        return count % 2 == 0;
+    }
+    true // WB999
+}
+
+// before 和 after 之间是否无论前后文如何都一定是单词边界和字形簇边界：
+// 换行之后；或者 before 是空白、标点等和两边都不相连的字符，after 不会附着在 before 上。
+// 在这样的位置把文本分成两段分别分词，结果和整体分词相同（用于分块读取，见 src/stream.rs）
+pub fn is_hard_boundary(before: char, after: char) -> bool {
+    if before == '\n' {
+        return true;
+    }
+    let (left, right) = (word_break(before), word_break(after));
+    matches!(left, WSegSpace | Other)
+        && script_of(before) == Script::Common
+        && grapheme_break(before) != GraphemeBreak::Prepend
+        && !is_ignorable(right)
+        && !(left == WSegSpace && right == WSegSpace)
+        && !matches!(grapheme_break(after), GraphemeBreak::Extend | GraphemeBreak::Zwj | GraphemeBreak::SpacingMark)
+}
+
+// 需要合并的连续片段的种类
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Run {
+    Han,
+    Unspaced, // 平假名和东南亚文字
+}
+
+fn run_of(segment: &str) -> Option<Run> {
+    match segment.chars().next().map(script_of) {
+        Some(Script::Han) => Some(Run::Han),
+        Some(script) if script == Script::Hiragana || script.is_complex() => Some(Run::Unspaced),
+        _ => None,
+    }
+}
+
+// 内置词表：每行一个词，# 开头的行是注释
+fn dictionary() -> &'static (HashSet<&'static str>, usize) {
+    static DICTIONARY: OnceLock<(HashSet<&'static str>, usize)> = OnceLock::new();
+    DICTIONARY.get_or_init(|| {
+        let words: HashSet<&str> = include_str!("cjk_words.txt")
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        let longest = words.iter().map(|word| word.chars().count()).max().unwrap_or(1);
+        (words, longest)
+    })
+}
+
+// 正向最大匹配：从左到右，每次取词表中最长的词；pieces 是 UAX #29 切出的单字片段
+fn match_han<'a>(text: &'a str, pieces: &[(usize, usize)], words: &mut Vec<&'a str>) {
+    let (dictionary, longest) = dictionary();
+    let mut index = 0;
+    while index < pieces.len() {
+        let start = pieces[index].0;
+        let mut length = (*longest).min(pieces.len() - index);
+        while length > 1 && !dictionary.contains(&text[start..pieces[index + length - 1].1]) {
+            length -= 1;
+        }
+        words.push(&text[start..pieces[index + length - 1].1]);
+        index += length;
+    }
+}
+
+// 输出正在合并的连续片段
+fn flush_run<'a>(text: &'a str, run: Option<Run>, pieces: &mut Vec<(usize, usize)>, words: &mut Vec<&'a str>) {
+    match run {
+        Some(Run::Han) => match_han(text, pieces, words),
+        Some(Run::Unspaced) => words.push(&text[pieces[0].0..pieces[pieces.len() - 1].1]),
+        None => {},
+    }
+    pieces.clear();
+}
+
+// 把文本切分为单词，顺序和原文相同
+pub fn words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut offset = 0;
+    // 正在合并的连续片段：种类和各片段的字节范围
+    let mut run: Option<Run> = None;
+    let mut pieces: Vec<(usize, usize)> = Vec::new();
+
+    for segment in split_word_bounds(text) {
+        let range = (offset, offset + segment.len());
+        offset += segment.len();
+
+        let kind = run_of(segment);
+        if kind != run {
+            flush_run(text, run, &mut pieces, &mut words);
+            run = kind;
+        }
+        if kind.is_some() {
+            pieces.push(range);
+        } else if segment.chars().any(char::is_alphabetic) {
+            words.push(segment);
+        }
+    }
+    flush_run(text, run, &mut pieces, &mut words);
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 按 WordBreakTest.txt 的写法检查边界：÷ 表示边界，× 表示不是边界，中间是十六进制码位
+    fn check_bounds(line: &str) {
+        let mut text = String::new();
+        let mut expected = Vec::new();
+        let mut segment = String::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => {
+                    if !segment.is_empty() {
+                        expected.push(std::mem::take(&mut segment));
+                    }
+                },
+                "×" => {},
+                code => {
+                    let ch = char::from_u32(u32::from_str_radix(code, 16).expect("十六进制码位")).expect("有效码位");
+                    text.push(ch);
+                    segment.push(ch);
+                },
+            }
+        }
+        assert_eq!(split_word_bounds(&text), expected, "{}", line);
+    }
+
+    #[test]
+    fn follows_word_break_test_lines() {
+        for line in [
+            "÷ 000D × 000A ÷ 0061 ÷",                 // WB3、WB3a
+            "÷ 0020 × 0020 ÷ 0061 ÷",                 // WB3d
+            "÷ 0061 × 00AD × 0062 ÷",                 // WB4：格式字符不影响边界
+            "÷ 0061 × 0027 × 0062 ÷",                 // WB6、WB7
+            "÷ 0061 ÷ 0027 ÷",                        // 后面没有字母时 ' 单独成段
+            "÷ 0031 × 002E × 0032 ÷",                 // WB11、WB12
+            "÷ 0031 × 0061 × 0032 ÷",                 // WB9、WB10
+            "÷ 0061 × 005F × 0062 ÷",                 // WB13a、WB13b
+            "÷ 30A2 × 30A2 ÷ 3042 ÷ 3042 ÷",          // WB13：片假名连在一起，平假名不连
+            "÷ 1F1E6 × 1F1E7 ÷ 1F1E8 ÷",              // WB15、WB16
+            "÷ 1F476 × 200D × 1F6D1 ÷",               // WB3c
+            "÷ 0061 ÷ 002C ÷ 0020 ÷ 0062 ÷",
+        ] {
+            check_bounds(line);
+        }
+    }
+
+    #[test]
+    fn keeps_apostrophes_and_splits_hyphens() {
+        assert_eq!(words("don't stop"), ["don't", "stop"]);
+        assert_eq!(words("Rust’s e-mail"), ["Rust’s", "e", "mail"]);
+        assert_eq!(split_word_bounds("e-mail"), ["e", "-", "mail"]);
+        assert_eq!(words("3.50 foo_bar 42"), ["foo_bar"]);
+    }
+
+    #[test]
+    fn segments_mixed_chinese_with_the_dictionary() {
+        assert_eq!(words("我们学习Rust编程语言。"), ["我们", "学习", "Rust", "编程语言"]);
+        assert_eq!(words("中华人民共和国"), ["中华人民共和国"]);
+        // 词表中没有的字单独成词
+        assert_eq!(words("猫狗"), ["猫", "狗"]);
+    }
+
+    #[test]
+    fn segments_sentences_from_the_notes() {
+        // 取自仓库根目录的 rust学习笔记.md
+        assert_eq!(
+            words("可以有多个返回值，和 go 类似，返回值的声明语法也和 go 类似"),
+            ["可以", "有", "多个", "返回值", "和", "go", "类似", "返回值", "的", "声明", "语法", "也", "和", "go", "类似"]
+        );
+        assert_eq!(
+            words("如果块的最后一行是表达式（没有分号），那么这个表达式的值就是整个块的返回值。"),
+            [
+                "如果", "块", "的", "最后", "一", "行", "是", "表达式", "没有", "分号", "那么", "这个", "表达式", "的", "值",
+                "就是", "整个", "块", "的", "返回值",
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_unspaced_scripts() {
+        assert_eq!(words("ひらがなです カタカナ"), ["ひらがなです", "カタカナ"]);
+        assert_eq!(words("สวัสดีครับ hello"), ["สวัสดีครับ", "hello"]);
+    }
+
+    #[test]
+    fn hard_boundaries_do_not_split_words_or_clusters() {
+        assert!(is_hard_boundary('\n', 'a'));
+        assert!(is_hard_boundary(' ', 'a'));
+        assert!(is_hard_boundary('!', '中'));
+        assert!(!is_hard_boundary('a', 'b'));
+        assert!(!is_hard_boundary('中', '文'));
+        assert!(!is_hard_boundary(' ', ' '));
+        assert!(!is_hard_boundary('.', 'a'));
+        // 组合符号和零宽连接符属于前一个字形簇
+        assert!(!is_hard_boundary(' ', '\u{301}'));
+        assert!(!is_hard_boundary('!', '\u{200D}'));
+    }
+}
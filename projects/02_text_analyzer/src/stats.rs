@@ -0,0 +1,375 @@
+// 文本统计 - 字符、单词、行和句子的统计，以及按选择的部分输出结果
+// 学习目标：结构体和枚举、HashMap、借用参数
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::category::{categorize_character, CharCategory};
+use crate::length::{LengthUnit, Lengths};
+use crate::script::{word_script, Script};
+use crate::segment;
+use crate::stream::Accumulator;
+
+// 要输出的统计部分，由命令行选项选择
+#[derive(Debug, Clone, Copy)]
+pub struct Sections {
+    pub lines: bool,       // 行数和句子数
+    pub words: bool,       // 单词数、平均长度、最长和最短单词、各文字系统的单词数
+    pub characters: bool,  // 四种单位的文本长度和字符分类
+    pub frequencies: bool, // 高频词
+}
+
+impl Sections {
+    // 全部输出（没有选择任何部分时的默认值）
+    pub fn all() -> Self {
+        Sections {
+            lines: true,
+            words: true,
+            characters: true,
+            frequencies: true,
+        }
+    }
+    
+    pub fn none() -> Self {
+        Sections {
+            lines: false,
+            words: false,
+            characters: false,
+            frequencies: false,
+        }
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        !(self.lines || self.words || self.characters || self.frequencies)
+    }
+}
+
+// 高频词默认显示的个数
+pub const DEFAULT_TOP: usize = 10;
+
+// 单词长度默认的单位
+pub const DEFAULT_UNIT: LengthUnit = LengthUnit::Graphemes;
+
+// 文本统计结构体（练习结构体定义）
+#[derive(Debug, Default, PartialEq)]
+pub struct TextStats {
+    pub text_length: Lengths, // 全文的字节数、码位数、字形簇数和显示宽度
+    pub total_words: usize,
+    pub total_lines: usize,
+    pub total_sentences: usize,
+    pub char_categories: HashMap<CharCategory, usize>,
+    pub word_frequencies: HashMap<String, usize>,
+    pub script_words: HashMap<Script, usize>,
+    pub word_length: Lengths,         // 所有单词的长度之和
+    pub longest_words: [String; 4],   // 按每种长度单位的最长单词，下标见 LengthUnit::index
+    pub shortest_words: [String; 4],  // 按每种长度单位的最短单词
+}
+
+impl TextStats {
+    // 创建新的统计实例
+    pub fn new() -> Self {
+        Self {
+            char_categories: HashMap::new(),
+            word_frequencies: HashMap::new(),
+            script_words: HashMap::new(),
+            ..Default::default()
+        }
+    }
+    
+    // 出现次数最多的 top 个单词；次数相同时按字母顺序，保证输出稳定
+    pub fn top_words(&self, top: usize) -> Vec<(&str, usize)> {
+        let mut word_vec: Vec<(&str, usize)> = self.word_frequencies
+            .iter()
+            .map(|(word, count)| (word.as_str(), *count))
+            .collect();
+        word_vec.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        word_vec.truncate(top);
+        word_vec
+    }
+    
+    // 按指定单位计算的平均单词长度
+    pub fn average_word_length(&self, unit: LengthUnit) -> f64 {
+        if self.total_words == 0 {
+            0.0
+        } else {
+            self.word_length.get(unit) as f64 / self.total_words as f64
+        }
+    }
+    
+    // 生成选中部分的结果文本，每个元素是一行；单词长度按 unit 计量
+    pub fn report(&self, sections: &Sections, top: usize, unit: LengthUnit) -> Vec<String> {
+        let mut lines = Vec::new();
+        
+        if sections.lines {
+            lines.push(format!("行数总数: {}", self.total_lines));
+            lines.push(format!("句子总数: {}", self.total_sentences));
+        }
+        
+        if sections.words {
+            lines.push(format!("单词总数: {}", self.total_words));
+            lines.push(format!("平均单词长度: {:.2} {}", self.average_word_length(unit), unit.label()));
+            for (label, word) in [("最长单词", &self.longest_words[unit.index()]), ("最短单词", &self.shortest_words[unit.index()])] {
+                if !word.is_empty() {
+                    lines.push(format!("{}: {} ({} {})", label, word, Lengths::of(word).get(unit), unit.label()));
+                }
+            }
+            if !self.script_words.is_empty() {
+                lines.push(String::new());
+                lines.push("=== 各文字系统的单词数 ===".to_string());
+                for script in Script::ALL {
+                    if let Some(count) = self.script_words.get(&script) {
+                        lines.push(format!("{:?}: {}", script, count));
+                    }
+                }
+            }
+        }
+        
+        if sections.characters {
+            lines.push(format!("字节数: {}", self.text_length.bytes));
+            lines.push(format!("码位数: {}", self.text_length.chars));
+            lines.push(format!("字形簇数: {}", self.text_length.graphemes));
+            lines.push(format!("显示宽度: {} 列", self.text_length.width));
+            lines.push(String::new());
+            lines.push("=== 字符分类统计 ===".to_string());
+            for category in CharCategory::ALL {
+                if let Some(count) = self.char_categories.get(&category) {
+                    lines.push(format!("{:?}: {}", category, count));
+                }
+            }
+        }
+        
+        if sections.frequencies {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!("=== 高频词汇 (前{}个) ===", top));
+            for (word, frequency) in self.top_words(top) {
+                lines.push(format!("{}: {} 次", word, frequency));
+            }
+        }
+        
+        lines
+    }
+    
+    // 累加一段文本的统计结果；行数由调用方统计
+    // 各段之间必须是单词和字形簇的边界（见 segment::is_hard_boundary），否则单词会被切断
+    pub fn add_text(&mut self, text: &str) {
+        // 统计字节数、码位数、字形簇数和显示宽度
+        self.text_length += Lengths::of(text);
+        
+        // 统计句子数
+        self.total_sentences += count_sentences(text);
+        
+        // 分析字符类别
+        analyze_characters(text, self);
+        
+        // 分析单词
+        analyze_words(text, self);
+    }
+    
+    // 合并另一份统计结果（例如另一个文件的），other 中的单词算作出现在 self 之后
+    pub fn merge(&mut self, other: TextStats) {
+        self.text_length += other.text_length;
+        self.total_words += other.total_words;
+        self.total_lines += other.total_lines;
+        self.total_sentences += other.total_sentences;
+        merge_counts(&mut self.char_categories, other.char_categories);
+        merge_counts(&mut self.word_frequencies, other.word_frequencies);
+        merge_counts(&mut self.script_words, other.script_words);
+        self.word_length += other.word_length;
+        
+        // 和 find_extremes 相同：长度相同时保留先出现的单词
+        let [longest, shortest] = [other.longest_words, other.shortest_words];
+        for (unit, (longer, shorter)) in LengthUnit::ALL.into_iter().zip(longest.into_iter().zip(shortest)) {
+            let index = unit.index();
+            if longer.is_empty() {
+                continue;
+            }
+            if self.longest_words[index].is_empty() {
+                self.longest_words[index] = longer;
+                self.shortest_words[index] = shorter;
+                continue;
+            }
+            if Lengths::of(&longer).get(unit) > Lengths::of(&self.longest_words[index]).get(unit) {
+                self.longest_words[index] = longer;
+            }
+            if Lengths::of(&shorter).get(unit) < Lengths::of(&self.shortest_words[index]).get(unit) {
+                self.shortest_words[index] = shorter;
+            }
+        }
+    }
+    
+    // 显示全部统计结果
+    pub fn display(&self) {
+        println!("\n=== 文本分析结果 ===");
+        for line in self.report(&Sections::all(), DEFAULT_TOP, DEFAULT_UNIT) {
+            println!("{}", line);
+        }
+    }
+}
+
+// 主要的文本分析函数（练习借用和所有权）
+// 和分块读取文件使用同样的累加器（见 src/stream.rs），保证两种方式的结果相同
+pub fn analyze_text(text: &str) -> TextStats {
+    let mut accumulator = Accumulator::with_max_pending(usize::MAX);
+    accumulator.push_str(text);
+    accumulator.finish_text()
+}
+
+// 把 other 中的计数加到 counts 中
+fn merge_counts<K: Eq + Hash>(counts: &mut HashMap<K, usize>, other: HashMap<K, usize>) {
+    for (key, count) in other {
+        *counts.entry(key).or_insert(0) += count;
+    }
+}
+
+// 统计句子数（练习字符处理）
+fn count_sentences(text: &str) -> usize {
+    let mut count = 0;
+    
+    for ch in text.chars() {
+        // 使用 match 进行模式匹配
+        match ch {
+            '.' | '!' | '?' => count += 1,
+            _ => continue,
+        }
+    }
+    
+    count
+}
+
+// 分析字符类别（练习字符分类和HashMap）
+fn analyze_characters(text: &str, stats: &mut TextStats) {
+    for ch in text.chars() {
+        let category = categorize_character(ch);
+        
+        // 更新字符类别计数
+        let count = stats.char_categories.entry(category).or_insert(0);
+        *count += 1;
+    }
+}
+
+// 分析单词（练习字符串处理和迭代）
+fn analyze_words(text: &str, stats: &mut TextStats) {
+    let words: Vec<String> = extract_words(text);
+    
+    if words.is_empty() {
+        return;
+    }
+    
+    stats.total_words += words.len();
+    
+    // 计算单词的总长度（平均长度在输出时按选择的单位计算），找最长和最短单词
+    find_extremes(&words, stats);
+    
+    // 计算词频
+    calculate_word_frequencies(&words, stats);
+    
+    // 按文字系统统计单词数
+    for word in &words {
+        *stats.script_words.entry(word_script(word)).or_insert(0) += 1;
+    }
+}
+
+// 提取单词：按 Unicode 单词边界分词（见 src/segment.rs），再统一大小写
+fn extract_words(text: &str) -> Vec<String> {
+    segment::words(text)
+        .into_iter()
+        .map(clean_word)
+        .collect()
+}
+
+// 清洗单词：转换为小写，弯撇号 ’ 统一为 '，让 "Don’t" 和 "don't" 算同一个词
+fn clean_word(word: &str) -> String {
+    word.replace('\u{2019}', "'").to_lowercase()
+}
+
+// 找到最长和最短单词（练习迭代器和比较）
+// 每种长度单位分别比较：按字节最长的单词不一定按显示宽度最长；长度相同时保留先出现的单词
+fn find_extremes(words: &[String], stats: &mut TextStats) {
+    // 之前各段中找到的最长和最短单词的长度
+    let mut longest = [0; 4];
+    let mut shortest = [usize::MAX; 4];
+    for unit in LengthUnit::ALL {
+        let index = unit.index();
+        if !stats.longest_words[index].is_empty() {
+            longest[index] = Lengths::of(&stats.longest_words[index]).get(unit);
+            shortest[index] = Lengths::of(&stats.shortest_words[index]).get(unit);
+        }
+    }
+    
+    for word in words {
+        let lengths = Lengths::of(word);
+        stats.word_length += lengths;
+        
+        for unit in LengthUnit::ALL {
+            let (index, length) = (unit.index(), lengths.get(unit));
+            if length > longest[index] {
+                longest[index] = length;
+                stats.longest_words[index] = word.clone();
+            }
+            if length < shortest[index] {
+                shortest[index] = length;
+                stats.shortest_words[index] = word.clone();
+            }
+        }
+    }
+}
+
+// 计算单词频率（练习HashMap操作）
+fn calculate_word_frequencies(words: &[String], stats: &mut TextStats) {
+    for word in words {
+        let count = stats.word_frequencies.entry(word.clone()).or_insert(0);
+        *count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_equals_analyzing_the_concatenation() {
+        // 第一段以换行结束时，合并两段的结果和分析拼接后的文本完全相同
+        let first = "Apples and pears. 我们学习编程\n";
+        let second = "strawberries, kiwis! 中文 ab\nlast";
+        let mut merged = analyze_text(first);
+        merged.merge(analyze_text(second));
+        assert_eq!(merged, analyze_text(&format!("{}{}", first, second)));
+        assert_eq!(merged.longest_words[LengthUnit::Chars.index()], "strawberries");
+        assert_eq!(merged.shortest_words[LengthUnit::Chars.index()], "我们");
+    }
+
+    #[test]
+    fn merging_keeps_the_earlier_word_on_ties() {
+        let mut merged = analyze_text("aaa b\n");
+        merged.merge(analyze_text("ccc d\n"));
+        assert_eq!(merged.longest_words[LengthUnit::Bytes.index()], "aaa");
+        assert_eq!(merged.shortest_words[LengthUnit::Bytes.index()], "b");
+        assert_eq!(merged.word_frequencies.len(), 4);
+        assert_eq!(merged.total_lines, 2);
+    }
+
+    #[test]
+    fn merging_into_empty_stats_copies_the_other() {
+        let mut merged = TextStats::new();
+        merged.merge(analyze_text("one two two\n"));
+        merged.merge(TextStats::new());
+        assert_eq!(merged, analyze_text("one two two\n"));
+    }
+
+    #[test]
+    fn counts_text_and_word_lengths_in_every_unit() {
+        // 单词是 naïve（按小写统计）、cafe + 组合重音、学习；手势表情不算单词，但计入全文长度
+        let stats = analyze_text("Naïve cafe\u{301} 学习 \u{1F44B}\u{1F3FD}\n");
+        assert_eq!(stats.total_words, 3);
+        assert_eq!(stats.text_length, Lengths { bytes: 30, chars: 18, graphemes: 16, width: 18 });
+        assert_eq!(stats.word_length, Lengths { bytes: 18, chars: 12, graphemes: 11, width: 13 });
+        assert_eq!(stats.longest_words[LengthUnit::Width.index()], "naïve");
+        assert_eq!(stats.shortest_words[LengthUnit::Chars.index()], "学习");
+        // cafe + 组合重音和学习都占四列，保留先出现的
+        assert_eq!(stats.shortest_words[LengthUnit::Width.index()], "cafe\u{301}");
+        assert_eq!(stats.shortest_words[LengthUnit::Graphemes.index()], "学习");
+    }
+
+}
@@ -0,0 +1,279 @@
+// 流式分析 - 分块读取文本并逐段累加统计结果，内存占用和文件大小无关
+// 学习目标：Read trait、缓冲区、处理被切断的 UTF-8 序列
+//
+// 每次读入一块字节：
+//   1. 块末尾可能是半个 UTF-8 字符（例如“中”的三个字节分在两块中），不完整的字节留到下一块
+//   2. 解码后的文本中，找到最后一个一定是单词边界的位置（换行、空白或标点之后），
+//      之前的文本交给 TextStats::add_text，之后的文本留下来和下一块拼接
+// 因此只需要保存最后一个边界之后的文本。连续很长一段都没有空白和标点时（例如不加标点的大段汉字、
+// 超长的 base64 行），留下的文本超过 MAX_PENDING 后强制在 UAX #29 单词边界处切开：这是近似处理，
+// 切开处的一个单词可能被分成两个（汉字的词表匹配、泰文等连续文字），其他统计结果不受影响。
+// 词频表的大小取决于不同单词的个数，和文件大小无关。
+
+use std::io::{self, Read};
+
+use crate::grapheme::graphemes;
+use crate::segment::{is_hard_boundary, split_word_bounds};
+use crate::stats::TextStats;
+
+// 每次读取的字节数
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// 没有找到一定是边界的位置时，最多留下这么多字节的文本，超过后强制切开
+const MAX_PENDING: usize = 1024 * 1024;
+
+// 逐块累加统计结果
+pub struct Accumulator {
+    stats: TextStats,
+    pending: String,         // 已解码、还没有统计的文本
+    checked: usize,          // pending 中这个下标之前的相邻字符已经确认不是分段位置
+    carry: Vec<u8>,          // 上一块末尾不完整的 UTF-8 序列（最多 3 个字节）
+    offset: u64,             // 已经读入的字节数，用于错误信息
+    newlines: usize,         // 已统计文本中的换行符个数
+    last_char: Option<char>, // 已统计文本的最后一个字符
+    max_pending: usize,      // 超过这个长度时强制切开 pending，见 MAX_PENDING
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Accumulator::new()
+    }
+}
+
+impl Accumulator {
+    pub fn new() -> Self {
+        Accumulator::with_max_pending(MAX_PENDING)
+    }
+
+    // 指定强制切开的长度；整段文本已经在内存中时（analyze_text）不需要强制切开，传入 usize::MAX
+    pub fn with_max_pending(max_pending: usize) -> Self {
+        Accumulator {
+            stats: TextStats::new(),
+            pending: String::new(),
+            checked: 0,
+            carry: Vec::new(),
+            offset: 0,
+            newlines: 0,
+            last_char: None,
+            max_pending,
+        }
+    }
+
+    // 读入一块字节；块的边界可以在 UTF-8 字符的中间
+    pub fn feed(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let mut data = std::mem::take(&mut self.carry);
+        data.extend_from_slice(bytes);
+        let start = self.offset - (data.len() - bytes.len()) as u64;
+        self.offset += bytes.len() as u64;
+
+        let valid = match std::str::from_utf8(&data) {
+            Ok(text) => text,
+            Err(error) => {
+                if error.error_len().is_some() {
+                    return Err(invalid_utf8(start + error.valid_up_to() as u64));
+                }
+                // 末尾是不完整的序列：留到下一块
+                self.carry = data[error.valid_up_to()..].to_vec();
+                std::str::from_utf8(&data[..error.valid_up_to()]).expect("前缀是有效的 UTF-8")
+            },
+        };
+        self.push_str(valid);
+        Ok(())
+    }
+
+    // 读入一段已经解码的文本
+    pub fn push_str(&mut self, text: &str) {
+        self.pending.push_str(text);
+        if let Some(cut) = self.last_boundary() {
+            self.consume(cut);
+        }
+        if self.pending.len() > self.max_pending {
+            self.consume(forced_boundary(&self.pending));
+        }
+        // 剩下的文本中相邻字符都已检查过，只有最后一个字符和下一块的第一个字符还没有检查
+        self.checked = self.pending.char_indices().next_back().map_or(0, |(index, _)| index);
+    }
+
+    // 输入结束，统计剩下的文本，返回结果；最后一块以不完整的 UTF-8 序列结尾时出错
+    pub fn finish(self) -> io::Result<TextStats> {
+        if !self.carry.is_empty() {
+            return Err(invalid_utf8(self.offset - self.carry.len() as u64));
+        }
+        Ok(self.finish_text())
+    }
+
+    // 只用 push_str 读入文本时结束，不会出错
+    pub fn finish_text(mut self) -> TextStats {
+        self.consume(self.pending.len());
+        // 和 str::lines 相同：最后一行没有换行符时也算一行
+        self.stats.total_lines = self.newlines + usize::from(self.last_char.is_some_and(|ch| ch != '\n'));
+        self.stats
+    }
+
+    // pending 中最后一个可以分段的位置，从末尾向前只检查新加入的部分
+    fn last_boundary(&self) -> Option<usize> {
+        let mut after: Option<(usize, char)> = None;
+        for (index, ch) in self.pending[self.checked..].char_indices().rev() {
+            if let Some((after_index, after_char)) = after {
+                if is_hard_boundary(ch, after_char) {
+                    return Some(self.checked + after_index);
+                }
+            }
+            after = Some((index, ch));
+        }
+        None
+    }
+
+    // 统计 pending 的前 end 个字节，并从 pending 中移除
+    fn consume(&mut self, end: usize) {
+        if end == 0 {
+            return;
+        }
+        let text = &self.pending[..end];
+        self.stats.add_text(text);
+        self.newlines += text.bytes().filter(|&byte| byte == b'\n').count();
+        self.last_char = text.chars().next_back();
+        self.pending.drain(..end);
+    }
+}
+
+// pending 太长时强制切开的位置：倒数第二个单词片段之前，使后面的文本不会影响这个边界附近的判断；
+// 整段只有一两个片段（一个超长的单词）时退回到最后一个字形簇之前
+fn forced_boundary(text: &str) -> usize {
+    let segments = split_word_bounds(text);
+    if segments.len() >= 3 {
+        return text.len() - segments[segments.len() - 2..].iter().map(|segment| segment.len()).sum::<usize>();
+    }
+    let clusters = graphemes(text);
+    match clusters.last() {
+        Some(last) if clusters.len() >= 2 => text.len() - last.len(),
+        _ => 0,
+    }
+}
+
+fn invalid_utf8(position: u64) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("第 {} 个字节处不是有效的 UTF-8", position + 1))
+}
+
+// 分块读取并分析全部内容
+pub fn analyze_reader(mut reader: impl Read) -> io::Result<TextStats> {
+    let mut accumulator = Accumulator::new();
+    let mut buffer = vec![0; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(count) => accumulator.feed(&buffer[..count])?,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    accumulator.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 包含 CRLF、组合符号、表情序列、旗帜、汉字、假名、泰文，最后一行没有换行符
+    const SAMPLE: &str = "Hello, wörld! Don’t panic.\r\n\
+        e\u{301}te\u{301} 👨‍👩‍👧 🇨🇳🇯🇵 — 3.50 foo_bar\n\
+        我们学习Rust编程语言。ひらがなです カタカナ\n\
+        สวัสดีครับ 한국어  tabs\tand   spaces?\n\
+        last line";
+
+    // 一次处理整段文本的结果，不经过 Accumulator 的分段
+    fn whole(text: &str) -> TextStats {
+        let mut stats = TextStats::new();
+        stats.add_text(text);
+        stats.total_lines = text.lines().count();
+        stats
+    }
+
+    fn feed_in_chunks(text: &str, size: usize) -> TextStats {
+        let mut accumulator = Accumulator::new();
+        for chunk in text.as_bytes().chunks(size) {
+            accumulator.feed(chunk).expect("有效的 UTF-8");
+        }
+        accumulator.finish().expect("输入完整")
+    }
+
+    #[test]
+    fn chunked_stats_match_the_whole_text() {
+        let expected = whole(SAMPLE);
+        for size in [1, 2, 3, 7, SAMPLE.len()] {
+            assert_eq!(feed_in_chunks(SAMPLE, size), expected, "每块 {} 个字节", size);
+        }
+        assert_eq!(analyze_reader(SAMPLE.as_bytes()).expect("有效的 UTF-8"), expected);
+    }
+
+    #[test]
+    fn joins_characters_split_between_chunks() {
+        let bytes = "中文".as_bytes();
+        let mut accumulator = Accumulator::new();
+        accumulator.feed(&bytes[..1]).expect("不完整的序列留到下一块");
+        accumulator.feed(&bytes[1..4]).expect("不完整的序列留到下一块");
+        accumulator.feed(&bytes[4..]).expect("不完整的序列留到下一块");
+        let stats = accumulator.finish().expect("输入完整");
+        assert_eq!(stats.text_length.chars, 2);
+        assert_eq!(stats.word_frequencies.get("中文"), Some(&1));
+    }
+
+    #[test]
+    fn reports_the_position_of_invalid_utf8() {
+        let error = Accumulator::new().feed(b"ab\xffcd").expect_err("无效的字节");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "第 3 个字节处不是有效的 UTF-8");
+
+        // 位置按整个输入计算，不是按块
+        let mut accumulator = Accumulator::new();
+        accumulator.feed(b"abc").expect("有效的 UTF-8");
+        accumulator.feed(b"\xe4").expect("不完整的序列留到下一块");
+        let error = accumulator.feed(b"x").expect_err("序列被打断");
+        assert_eq!(error.to_string(), "第 4 个字节处不是有效的 UTF-8");
+
+        // 输入在字符中间结束
+        let mut accumulator = Accumulator::new();
+        accumulator.feed(b"abc\xe4\xb8").expect("不完整的序列留到下一块");
+        let error = accumulator.finish().expect_err("输入不完整");
+        assert_eq!(error.to_string(), "第 4 个字节处不是有效的 UTF-8");
+    }
+
+    #[test]
+    fn checks_the_pair_across_the_chunk_boundary() {
+        // 上一块的最后一个字符和下一块的第一个字符之间也要检查
+        let mut accumulator = Accumulator::new();
+        accumulator.push_str("ab ");
+        assert_eq!(accumulator.pending, "ab ");
+        accumulator.push_str("cd");
+        assert_eq!(accumulator.pending, "cd");
+        assert_eq!(accumulator.stats.total_words, 1);
+
+        // 不是边界时整段留下，单词不会被切断
+        accumulator.push_str("ef");
+        assert_eq!(accumulator.pending, "cdef");
+        assert_eq!(accumulator.finish_text().word_frequencies.get("cdef"), Some(&1));
+    }
+
+    #[test]
+    fn forces_a_cut_when_no_boundary_is_found() {
+        let text = "汉字".repeat(200);
+        let mut accumulator = Accumulator::with_max_pending(64);
+        for chunk in text.as_bytes().chunks(10) {
+            accumulator.feed(chunk).expect("有效的 UTF-8");
+            assert!(accumulator.pending.len() <= 64 + 10, "留下了 {} 个字节", accumulator.pending.len());
+        }
+        let stats = accumulator.finish().expect("输入完整");
+        // 强制切开只影响分词（切开处的词可能被分成两个），长度、字符分类和单词的总长度不变
+        let expected = whole(&text);
+        assert_eq!(stats.text_length, expected.text_length);
+        assert_eq!(stats.char_categories, expected.char_categories);
+        assert_eq!(stats.word_length, expected.word_length);
+        assert!(stats.total_words >= expected.total_words);
+
+        // 一个超长的单词也会被切开
+        let mut accumulator = Accumulator::with_max_pending(64);
+        accumulator.push_str(&"a".repeat(1000));
+        assert!(accumulator.pending.len() <= 64);
+        assert_eq!(accumulator.finish_text().text_length.chars, 1000);
+    }
+}
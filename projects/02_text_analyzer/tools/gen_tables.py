@@ -0,0 +1,327 @@
+#!/usr/bin/env python3
+# Unicode 属性表生成器 - 生成 src/tables.rs
+# 用法：python3 tools/gen_tables.py > src/tables.rs
+#
+# 只使用 Python 自带的 unicodedata 模块（通用类别、东亚宽度、字符名称）：通用类别（General_Category）
+# 和东亚宽度直接输出，Word_Break、Grapheme_Cluster_Break 属性按 UAX #29 中各属性值的定义推导，
+# 文字系统（Script）按字符名称的前缀判断。
+# unicodedata 没有的少量属性（Other_Grapheme_Extend、Extended_Pictographic 等）直接写在下面的列表中。
+# 生成的表是 (起点, 终点, 值) 的有序区间列表，值相同的相邻区间合并，没有列出的字符取默认值。
+
+import sys
+import unicodedata
+
+MAX_CODE_POINT = 0x10FFFF
+
+# 下面手写的 Prepend、Extended_Pictographic 和 Word_Break 列表都是 14.0 的数据，
+# 换了 Python 版本后 unicodedata 的版本可能不同，两者混用会生成不一致的表
+assert unicodedata.unidata_version == "14.0.0", "需要 Unicode 14.0.0 的 unicodedata，当前是 %s" % unicodedata.unidata_version
+
+
+def ranges(*items):
+    # 把 0x41 和 (0x41, 0x5A) 形式的条目展开为集合
+    result = set()
+    for item in items:
+        if isinstance(item, tuple):
+            result.update(range(item[0], item[1] + 1))
+        else:
+            result.add(item)
+    return result
+
+
+# 不在 unicodedata 中的属性数据（Unicode 14.0 的 PropList.txt、emoji-data.txt）
+OTHER_GRAPHEME_EXTEND = ranges(
+    0x09BE, 0x09D7, 0x0B3E, 0x0B57, 0x0BBE, 0x0BD7, 0x0CC2, (0x0CD5, 0x0CD6), 0x0D3E, 0x0D57,
+    0x0DCF, 0x0DDF, 0x1B35, 0x200C, (0x302E, 0x302F), (0xFF9E, 0xFF9F), 0x1133E, 0x11357,
+    0x114B0, 0x114BD, 0x115AF, 0x11930, 0x1D165, (0x1D16E, 0x1D172), (0xE0020, 0xE007F),
+)
+EMOJI_MODIFIER = ranges((0x1F3FB, 0x1F3FF))
+EXTENDED_PICTOGRAPHIC = ranges(
+    0x00A9, 0x00AE, 0x203C, 0x2049, 0x2122, 0x2139, (0x2194, 0x2199), (0x21A9, 0x21AA),
+    (0x231A, 0x231B), 0x2328, 0x2388, 0x23CF, (0x23E9, 0x23F3), (0x23F8, 0x23FA), 0x24C2,
+    (0x25AA, 0x25AB), 0x25B6, 0x25C0, (0x25FB, 0x25FE), (0x2600, 0x2605), (0x2607, 0x2612),
+    (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712), 0x2714, 0x2716, 0x271D, 0x2721,
+    0x2728, (0x2733, 0x2734), 0x2744, 0x2747, 0x274C, 0x274E, (0x2753, 0x2755), 0x2757,
+    (0x2763, 0x2767), (0x2795, 0x2797), 0x27A1, 0x27B0, 0x27BF, (0x2934, 0x2935),
+    (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), 0x2B50, 0x2B55, 0x3030, 0x303D, 0x3297, 0x3299,
+    (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F), 0x1F12F, (0x1F16C, 0x1F171), (0x1F17E, 0x1F17F),
+    0x1F18E, (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5), (0x1F201, 0x1F20F), 0x1F21A, 0x1F22F,
+    (0x1F232, 0x1F23A), (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA), (0x1F400, 0x1F53D),
+    (0x1F546, 0x1F64F), (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F), (0x1F7D5, 0x1F7FF),
+    (0x1F80C, 0x1F80F), (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F), (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8FF), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+)
+# 属于 Other_Alphabetic 但通用类别是 So 的字母符号（圆圈字母、方框字母）
+OTHER_ALPHABETIC_SYMBOLS = ranges(
+    (0x24B6, 0x24E9), (0x1F130, 0x1F149), (0x1F150, 0x1F169), (0x1F170, 0x1F189),
+)
+# Grapheme_Cluster_Break=Prepend 的格式字符，不算 Word_Break=Format
+PREPENDED_FORMAT = ranges((0x0600, 0x0605), 0x06DD, 0x070F, (0x0890, 0x0891), 0x08E2, 0x110BD, 0x110CD)
+# Line_Break=Complex_Context 的文字（泰文、老挝文、缅甸文、高棉文等），需要词典分词
+COMPLEX_CONTEXT = ranges(
+    (0x0E00, 0x0E7F), (0x0E80, 0x0EFF), (0x1000, 0x109F), (0x1780, 0x17FF), (0x1950, 0x197F),
+    (0x1980, 0x19DF), (0x19E0, 0x19FF), (0x1A20, 0x1AAF), (0xA9E0, 0xA9FF), (0xAA60, 0xAA7F),
+    (0xAA80, 0xAADF), (0x11700, 0x1174F),
+)
+
+# Grapheme_Cluster_Break=Prepend（Prepended_Concatenation_Mark 和部分印度系文字的前置辅音）
+GRAPHEME_PREPEND = ranges(
+    (0x0600, 0x0605), 0x06DD, 0x070F, (0x0890, 0x0891), 0x08E2, 0x0D4E, 0x110BD, 0x110CD,
+    (0x111C2, 0x111C3), 0x1193F, 0x11941, 0x11A3A, (0x11A84, 0x11A89), 0x11D46,
+)
+# 通用类别是 Mc 但不算 SpacingMark 的字符，以及算作 SpacingMark 的泰文、老挝文元音
+SPACING_MARK_EXCLUDED = ranges(
+    0x102B, 0x102C, 0x1038, (0x1062, 0x1064), (0x1067, 0x106D), 0x1083, (0x1087, 0x108C), 0x108F,
+    (0x109A, 0x109C), 0x1A61, (0x1A63, 0x1A64), 0xAA7B, 0xAA7D, 0x11720, 0x11721,
+)
+SPACING_MARK_EXTRA = ranges(0x0E33, 0x0EB3)
+
+# Word_Break 中直接列出字符的属性值
+WORD_BREAK_LISTS = {
+    "Newline": ranges(0x000B, 0x000C, 0x0085, 0x2028, 0x2029),
+    "SingleQuote": ranges(0x0027),
+    "DoubleQuote": ranges(0x0022),
+    "MidNumLet": ranges(0x002E, 0x2018, 0x2019, 0x2024, 0xFE52, 0xFF07, 0xFF0E),
+    "MidLetter": ranges(0x003A, 0x00B7, 0x0387, 0x055F, 0x05F4, 0x2027, 0xFE13, 0xFE55, 0xFF1A),
+    "MidNum": ranges(
+        0x002C, 0x003B, 0x037E, 0x0589, 0x060C, 0x060D, 0x066C, 0x07F8, 0x2044, 0xFE10, 0xFE14,
+        0xFE50, 0xFE54, 0xFF0C, 0xFF1B,
+    ),
+}
+# ALetter 中通用类别不是字母的字符（修饰符号、亚美尼亚标点等）
+ALETTER_EXTRA = ranges(
+    (0x02C2, 0x02C5), (0x02D2, 0x02D7), (0x02DE, 0x02DF), (0x02E5, 0x02EB), 0x02ED,
+    (0x02EF, 0x02FF), (0x055A, 0x055C), 0x055E, 0x058A, 0x05F3, (0xA708, 0xA716),
+    (0xA720, 0xA721), (0xA789, 0xA78A), 0xAB5B,
+)
+KATAKANA_EXTRA = ranges((0x3031, 0x3035), (0x309B, 0x309C), 0x30A0, 0x30FC, 0xFF70, (0x3300, 0x3357))
+IDEOGRAPHIC_EXTRA = ranges(0x3006, 0x3007, (0x3021, 0x3029), (0x3038, 0x303A))
+
+# 判断文字系统的字符名称前缀，按顺序匹配；Han 单独处理
+SCRIPTS = [
+    ("Latin", ["LATIN", "FULLWIDTH LATIN", "CIRCLED LATIN", "PARENTHESIZED LATIN"]),
+    ("Greek", ["GREEK"]),
+    ("Cyrillic", ["CYRILLIC"]),
+    ("Armenian", ["ARMENIAN"]),
+    ("Hebrew", ["HEBREW"]),
+    ("Arabic", ["ARABIC"]),
+    ("Devanagari", ["DEVANAGARI"]),
+    ("Bengali", ["BENGALI"]),
+    ("Thai", ["THAI"]),
+    ("Lao", ["LAO"]),
+    ("Myanmar", ["MYANMAR"]),
+    ("Khmer", ["KHMER"]),
+    ("Georgian", ["GEORGIAN"]),
+    ("Hangul", ["HANGUL"]),
+    ("Hiragana", ["HIRAGANA", "HENTAIGANA"]),
+    ("Katakana", ["KATAKANA", "HALFWIDTH KATAKANA", "CIRCLED KATAKANA"]),
+]
+
+
+def is_ideographic(code, name):
+    return (
+        name.startswith("CJK UNIFIED IDEOGRAPH")
+        or name.startswith("CJK COMPATIBILITY IDEOGRAPH")
+        or name.startswith("TANGUT")
+        or name.startswith("NUSHU CHARACTER")
+        or name.startswith("KHITAN SMALL SCRIPT CHARACTER")
+        or code in IDEOGRAPHIC_EXTRA
+    )
+
+
+def name_of(code):
+    # CJK 统一汉字的名称由 unicodedata 按码位生成，这里不需要特殊处理
+    return unicodedata.name(chr(code), "")
+
+
+def word_break(code):
+    ch = chr(code)
+    category = unicodedata.category(ch)
+    name = name_of(code)
+    if code == 0x0D:
+        return "CR"
+    if code == 0x0A:
+        return "LF"
+    for value, members in WORD_BREAK_LISTS.items():
+        if code in members:
+            return value
+    if code == 0x200D:
+        return "Zwj"
+    if 0x1F1E6 <= code <= 0x1F1FF:
+        return "RegionalIndicator"
+    # Extend = Grapheme_Extend + 间距组合符号 + 表情修饰符
+    if category in ("Mn", "Me", "Mc") or code in OTHER_GRAPHEME_EXTEND or code in EMOJI_MODIFIER:
+        return "Extend"
+    if category == "Cf" and code not in (0x200B, 0x200C, 0x200D) and code not in PREPENDED_FORMAT:
+        return "Format"
+    if "KATAKANA" in name.split()[0:2] or name.startswith("HALFWIDTH KATAKANA") or code in KATAKANA_EXTRA:
+        return "Katakana"
+    if category == "Lo" and name.startswith("HEBREW"):
+        return "HebrewLetter"
+    alphabetic = category in ("Lu", "Ll", "Lt", "Lm", "Lo", "Nl") or code in OTHER_ALPHABETIC_SYMBOLS
+    if (alphabetic or code in ALETTER_EXTRA) and not (
+        is_ideographic(code, name)
+        or code in COMPLEX_CONTEXT
+        or name.startswith("HIRAGANA")
+        or name.startswith("HENTAIGANA")
+        or code == 0x1F200
+    ):
+        return "ALetter"
+    if (category == "Nd" and not 0xFF10 <= code <= 0xFF19) or code == 0x066B:
+        return "Numeric"
+    if category == "Pc" or code == 0x202F:
+        return "ExtendNumLet"
+    if category == "Zs" and code not in (0x00A0, 0x2007, 0x202F):
+        return "WSegSpace"
+    return None
+
+
+def script(code):
+    ch = chr(code)
+    category = unicodedata.category(ch)
+    # 只给字母、组合符号和字母数字标注文字系统，数字和标点等是通用字符
+    if category[0] not in "LM" and category != "Nl" and code not in OTHER_ALPHABETIC_SYMBOLS:
+        return None
+    name = name_of(code)
+    if is_ideographic(code, name) or code == 0x3005 or code == 0x303B:
+        return "Han"
+    for value, prefixes in SCRIPTS:
+        if any(name.startswith(prefix + " ") or name.startswith(prefix + "-") for prefix in prefixes):
+            return value
+    return None
+
+
+def grapheme_break(code):
+    category = unicodedata.category(chr(code))
+    if code == 0x0D:
+        return "CR"
+    if code == 0x0A:
+        return "LF"
+    if code == 0x200D:
+        return "Zwj"
+    if 0x1F1E6 <= code <= 0x1F1FF:
+        return "RegionalIndicator"
+    if code in GRAPHEME_PREPEND:
+        return "Prepend"
+    if category in ("Mn", "Me") or code in OTHER_GRAPHEME_EXTEND or code in EMOJI_MODIFIER:
+        return "Extend"
+    if (category == "Mc" and code not in SPACING_MARK_EXCLUDED) or code in SPACING_MARK_EXTRA:
+        return "SpacingMark"
+    if category in ("Zl", "Zp", "Cc") or (category == "Cf" and code not in (0x200C, 0x200D)):
+        return "Control"
+    # 谚文字母（音节 AC00–D7A3 在代码中计算，不放在表中）
+    if 0x1100 <= code <= 0x115F or 0xA960 <= code <= 0xA97C:
+        return "L"
+    if 0x1160 <= code <= 0x11A7 or 0xD7B0 <= code <= 0xD7C6:
+        return "V"
+    if 0x11A8 <= code <= 0x11FF or 0xD7CB <= code <= 0xD7FB:
+        return "T"
+    return None
+
+
+def is_wide(code):
+    # 东亚宽度为 W（宽）或 F（全角）的字符占两列
+    return unicodedata.east_asian_width(chr(code)) in ("W", "F")
+
+
+GENERAL_CATEGORIES = {
+    "Lu": "UppercaseLetter", "Ll": "LowercaseLetter", "Lt": "TitlecaseLetter", "Lm": "ModifierLetter",
+    "Lo": "OtherLetter", "Mn": "NonspacingMark", "Mc": "SpacingMark", "Me": "EnclosingMark",
+    "Nd": "DecimalNumber", "Nl": "LetterNumber", "No": "OtherNumber", "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation", "Ps": "OpenPunctuation", "Pe": "ClosePunctuation", "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation", "Po": "OtherPunctuation", "Sm": "MathSymbol", "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol", "So": "OtherSymbol", "Zs": "SpaceSeparator", "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator", "Cc": "Control", "Cf": "Format", "Co": "PrivateUse",
+}
+
+
+def general_category(code):
+    # 未分配的字符（Cn）是默认值，不输出
+    return GENERAL_CATEGORIES.get(unicodedata.category(chr(code)))
+
+
+def build(function):
+    # 把每个码位的值合并为区间；None 表示默认值，不输出
+    table = []
+    for code in range(MAX_CODE_POINT + 1):
+        if 0xD800 <= code <= 0xDFFF:
+            continue
+        value = function(code)
+        if value is None:
+            continue
+        if table and table[-1][2] == value and table[-1][1] in (code - 1, 0xD7FF):
+            table[-1][1] = code
+        else:
+            table.append([code, code, value])
+    return table
+
+
+def emit_set(out, name, members):
+    out.write("pub const %s: &[(u32, u32)] = &[\n" % name)
+    table = build(lambda code: True if code in members else None)
+    for start, end, _ in table:
+        out.write("    (0x%04X, 0x%04X),\n" % (start, end))
+    out.write("];\n")
+
+
+def emit(out, name, type_name, table):
+    out.write("pub const %s: &[(u32, u32, %s)] = &[\n" % (name, type_name))
+    for start, end, value in table:
+        out.write("    (0x%04X, 0x%04X, %s::%s),\n" % (start, end, type_name, value))
+    out.write("];\n")
+
+
+def main():
+    out = sys.stdout
+    out.write("// Unicode 属性表 - 由 tools/gen_tables.py 根据 Unicode %s 数据生成，不要手工修改\n" % unicodedata.unidata_version)
+    out.write("// 学习目标：用有序区间表和二分查找保存大量的字符属性\n\n")
+    out.write("use crate::category::GeneralCategory;\n")
+    out.write("use crate::grapheme::GraphemeBreak;\n")
+    out.write("use crate::script::Script;\n")
+    out.write("use crate::segment::WordBreak;\n\n")
+    out.write("// 在区间表中查找字符的属性值，不在任何区间中时返回 None\n")
+    out.write("pub fn lookup<T: Copy>(table: &[(u32, u32, T)], ch: char) -> Option<T> {\n")
+    out.write("    let code = ch as u32;\n")
+    out.write("    table\n")
+    out.write("        .binary_search_by(|&(start, end, _)| {\n")
+    out.write("            if end < code {\n")
+    out.write("                std::cmp::Ordering::Less\n")
+    out.write("            } else if start > code {\n")
+    out.write("                std::cmp::Ordering::Greater\n")
+    out.write("            } else {\n")
+    out.write("                std::cmp::Ordering::Equal\n")
+    out.write("            }\n")
+    out.write("        })\n")
+    out.write("        .ok()\n")
+    out.write("        .map(|index| table[index].2)\n")
+    out.write("}\n\n")
+    out.write("// 字符是否在区间集合中\n")
+    out.write("pub fn contains(set: &[(u32, u32)], ch: char) -> bool {\n")
+    out.write("    let code = ch as u32;\n")
+    out.write("    set.binary_search_by(|&(start, end)| {\n")
+    out.write("        if end < code {\n")
+    out.write("            std::cmp::Ordering::Less\n")
+    out.write("        } else if start > code {\n")
+    out.write("            std::cmp::Ordering::Greater\n")
+    out.write("        } else {\n")
+    out.write("            std::cmp::Ordering::Equal\n")
+    out.write("        }\n")
+    out.write("    })\n")
+    out.write("    .is_ok()\n")
+    out.write("}\n\n")
+    emit(out, "WORD_BREAK", "WordBreak", build(word_break))
+    out.write("\n")
+    emit(out, "SCRIPT", "Script", build(script))
+    out.write("\n")
+    emit(out, "GENERAL_CATEGORY", "GeneralCategory", build(general_category))
+    out.write("\n")
+    emit_set(out, "EXTENDED_PICTOGRAPHIC", EXTENDED_PICTOGRAPHIC)
+    out.write("\n")
+    emit(out, "GRAPHEME_BREAK", "GraphemeBreak", build(grapheme_break))
+    out.write("\n")
+    emit_set(out, "WIDE", set(code for code in range(MAX_CODE_POINT + 1) if is_wide(code)))
+
+
+if __name__ == "__main__":
+    main()